@@ -2,13 +2,79 @@ use std::ops::{Mul, Add, Sub};
 
 use num_traits::real::Real;
 use super::traits::Pi;
+use super::vectors::{Vector2, Vector3, Vector4};
 
-fn interpolate<T>(a: T, b: T, t: T) -> T
+pub fn interpolate<T>(a: T, b: T, t: T) -> T
 where T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Copy {
     a + (b - a) * t
 }
 
-struct Ease;
+/// Values that can be linearly interpolated by a factor of type `T`, so tweening code
+/// can drive scalars and vectors alike.
+pub trait Lerp<T> {
+    fn lerp(self, other: Self, t: T) -> Self;
+}
+
+impl Lerp<f32> for f32 {
+    #[inline]
+    fn lerp(self, other: Self, t: f32) -> Self {
+        interpolate(self, other, t)
+    }
+}
+
+impl Lerp<f64> for f64 {
+    #[inline]
+    fn lerp(self, other: Self, t: f64) -> Self {
+        interpolate(self, other, t)
+    }
+}
+
+impl<T> Lerp<T> for Vector2<T>
+where T: Real {
+    #[inline]
+    fn lerp(self, other: Self, t: T) -> Self {
+        Vector2::lerp(self, other, t)
+    }
+}
+
+impl<T> Lerp<T> for Vector3<T>
+where T: Real {
+    #[inline]
+    fn lerp(self, other: Self, t: T) -> Self {
+        Vector3::lerp(self, other, t)
+    }
+}
+
+impl<T> Lerp<T> for Vector4<T>
+where T: Real {
+    #[inline]
+    fn lerp(self, other: Self, t: T) -> Self {
+        Vector4::lerp(self, other, t)
+    }
+}
+
+/// Hermite interpolation between `edge0` and `edge1`. `x` is clamped into the range
+/// first, so anything below `edge0` returns `0` and anything above `edge1` returns `1`.
+pub fn smoothstep<T>(edge0: T, edge1: T, x: T) -> T
+where T: Real {
+    let t = ((x - edge0) / (edge1 - edge0)).max(T::zero()).min(T::one());
+    let two = T::one() + T::one();
+    t * t * (two + T::one() - two * t)
+}
+
+/// Ken Perlin's smootherstep, which also has zero second derivatives at the edges.
+/// Clamps `x` the same way as [`smoothstep`].
+pub fn smootherstep<T>(edge0: T, edge1: T, x: T) -> T
+where T: Real {
+    let t = ((x - edge0) / (edge1 - edge0)).max(T::zero()).min(T::one());
+    let two = T::one() + T::one();
+    let three = two + T::one();
+    let five = three + two;
+    let six = three + three;
+    t * t * t * (t * (t * six - five * three) + five * two)
+}
+
+pub struct Ease;
 
 impl Ease {
     pub fn clamp<T>(&self, t: T) -> T
@@ -23,12 +89,12 @@ impl Ease {
 
     pub fn sine_in<T>(&self, t: T) -> T
     where T: Real + Pi<Output = T> {
-        T::one() - (t * T::pi() / (T::one() + T::one())).cos()
+        T::one() - (t * T::half_pi()).cos()
     }
 
     pub fn sine_out<T>(&self, t: T) -> T
     where T: Real + Pi<Output = T> {
-        (t * T::pi() / (T::one() + T::one())).sin()
+        (t * T::half_pi()).sin()
     }
 
     pub fn sine_inout<T>(&self, t: T) -> T
@@ -269,16 +335,16 @@ impl Ease {
 
     pub fn back_inout<T>(&self, t: T) -> T
     where T: Real {
-        let c3 = T::from(3.22658).unwrap();
+        let c2 = T::from(2.5949095).unwrap();
 
         let two = T::one() + T::one();
 
         if t < T::one() / two {
             let v = two * t;
-            v * v * ((c3 + T::one()) * two * t - c3) / two
+            v * v * ((c2 + T::one()) * v - c2) / two
         } else {
             let v = two * t - two;
-            v * v * (((c3 + T::one()) * v + c3) + two) / two
+            (v * v * ((c2 + T::one()) * v + c2) + two) / two
         }
     }
 
@@ -287,7 +353,7 @@ impl Ease {
         let two = T::one() + T::one();
         let three = two + T::one();
         let four = two + two;
-        let c4 = two * T::pi() / (T::one() / three);
+        let c4 = T::tau() / three;
         let ten = four + four + two;
         let ten_and_three_quarters = ten + three / four;
 
@@ -299,6 +365,178 @@ impl Ease {
             -two.powf(ten * t - ten) * ((t * ten - ten_and_three_quarters) * c4).sin()
         }
     }
+
+    pub fn elastic_out<T>(&self, t: T) -> T
+    where T: Real + Pi<Output = T> {
+        let two = T::one() + T::one();
+        let three = two + T::one();
+        let four = two + two;
+        let c4 = T::tau() / three;
+        let ten = four + four + two;
+        let three_quarters = three / four;
+
+        if t == T::zero() {
+            T::zero()
+        } else if t == T::one() {
+            T::one()
+        } else {
+            two.powf(-ten * t) * ((t * ten - three_quarters) * c4).sin() + T::one()
+        }
+    }
+
+    pub fn elastic_inout<T>(&self, t: T) -> T
+    where T: Real + Pi<Output = T> {
+        let two = T::one() + T::one();
+        let c5 = T::tau() / T::from(4.5).unwrap();
+        let ten = T::from(10.0).unwrap();
+        let eleven_and_an_eighth = T::from(11.125).unwrap();
+
+        if t == T::zero() {
+            T::zero()
+        } else if t == T::one() {
+            T::one()
+        } else if t < T::one() / two {
+            -(two.powf((ten + ten) * t - ten) * (((ten + ten) * t - eleven_and_an_eighth) * c5).sin()) / two
+        } else {
+            two.powf(-(ten + ten) * t + ten) * (((ten + ten) * t - eleven_and_an_eighth) * c5).sin() / two + T::one()
+        }
+    }
+
+    pub fn bounce_in<T>(&self, t: T) -> T
+    where T: Real {
+        T::one() - self.bounce_out(T::one() - t)
+    }
+
+    pub fn bounce_out<T>(&self, t: T) -> T
+    where T: Real {
+        let n1 = T::from(7.5625).unwrap();
+        let d1 = T::from(2.75).unwrap();
+
+        if t == T::zero() {
+            T::zero()
+        } else if t == T::one() {
+            T::one()
+        } else if t < T::one() / d1 {
+            n1 * t * t
+        } else if t < T::from(2.0).unwrap() / d1 {
+            let v = t - T::from(1.5).unwrap() / d1;
+            n1 * v * v + T::from(0.75).unwrap()
+        } else if t < T::from(2.5).unwrap() / d1 {
+            let v = t - T::from(2.25).unwrap() / d1;
+            n1 * v * v + T::from(0.9375).unwrap()
+        } else {
+            let v = t - T::from(2.625).unwrap() / d1;
+            n1 * v * v + T::from(0.984375).unwrap()
+        }
+    }
+
+    pub fn bounce_inout<T>(&self, t: T) -> T
+    where T: Real {
+        let two = T::one() + T::one();
+        if t < T::one() / two {
+            (T::one() - self.bounce_out(T::one() - two * t)) / two
+        } else {
+            (T::one() + self.bounce_out(two * t - T::one())) / two
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Easing {
+    Linear,
+    SineIn,
+    SineOut,
+    SineInOut,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    QuartIn,
+    QuartOut,
+    QuartInOut,
+    QuintIn,
+    QuintOut,
+    QuintInOut,
+    SextIn,
+    SextOut,
+    SextInOut,
+    SeptIn,
+    SeptOut,
+    SeptInOut,
+    OctIn,
+    OctOut,
+    OctInOut,
+    ExpoIn,
+    ExpoOut,
+    ExpoInOut,
+    CircIn,
+    CircOut,
+    CircInOut,
+    BackIn,
+    BackOut,
+    BackInOut,
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+}
+
+impl Easing {
+    pub fn apply<T>(&self, t: T) -> T
+    where T: Real + Pi<Output = T> {
+        match self {
+            Easing::Linear => Ease.linear(t),
+            Easing::SineIn => Ease.sine_in(t),
+            Easing::SineOut => Ease.sine_out(t),
+            Easing::SineInOut => Ease.sine_inout(t),
+            Easing::QuadIn => Ease.quad_in(t),
+            Easing::QuadOut => Ease.quad_out(t),
+            Easing::QuadInOut => Ease.quad_inout(t),
+            Easing::CubicIn => Ease.cubic_in(t),
+            Easing::CubicOut => Ease.cubic_out(t),
+            Easing::CubicInOut => Ease.cubic_inout(t),
+            Easing::QuartIn => Ease.quart_in(t),
+            Easing::QuartOut => Ease.quart_out(t),
+            Easing::QuartInOut => Ease.quart_inout(t),
+            Easing::QuintIn => Ease.quint_in(t),
+            Easing::QuintOut => Ease.quint_out(t),
+            Easing::QuintInOut => Ease.quint_inout(t),
+            Easing::SextIn => Ease.sext_in(t),
+            Easing::SextOut => Ease.sext_out(t),
+            Easing::SextInOut => Ease.sext_inout(t),
+            Easing::SeptIn => Ease.sept_in(t),
+            Easing::SeptOut => Ease.sept_out(t),
+            Easing::SeptInOut => Ease.sept_inout(t),
+            Easing::OctIn => Ease.oct_in(t),
+            Easing::OctOut => Ease.oct_out(t),
+            Easing::OctInOut => Ease.oct_inout(t),
+            Easing::ExpoIn => Ease.expo_in(t),
+            Easing::ExpoOut => Ease.expo_out(t),
+            Easing::ExpoInOut => Ease.expo_inout(t),
+            Easing::CircIn => Ease.circ_in(t),
+            Easing::CircOut => Ease.circ_out(t),
+            Easing::CircInOut => Ease.circ_inout(t),
+            Easing::BackIn => Ease.back_in(t),
+            Easing::BackOut => Ease.back_out(t),
+            Easing::BackInOut => Ease.back_inout(t),
+            Easing::ElasticIn => Ease.elastic_in(t),
+            Easing::ElasticOut => Ease.elastic_out(t),
+            Easing::ElasticInOut => Ease.elastic_inout(t),
+            Easing::BounceIn => Ease.bounce_in(t),
+            Easing::BounceOut => Ease.bounce_out(t),
+            Easing::BounceInOut => Ease.bounce_inout(t),
+        }
+    }
+
+    /// Eases `t` and interpolates from `from` to `to` by the result.
+    pub fn apply_between<T, V>(&self, from: V, to: V, t: T) -> V
+    where T: Real + Pi<Output = T>, V: Lerp<T> {
+        from.lerp(to, self.apply(t))
+    }
 }
 
 #[cfg(test)]
@@ -314,6 +552,108 @@ mod tests {
         assert_eq!(interpolate(0.0, 1.0, 0.75), 0.75);
     }
 
+    #[test]
+    fn smoothstep() {
+        use super::smoothstep;
+
+        assert_eq!(smoothstep(0.0, 1.0, 0.0), 0.0);
+        assert_eq!(smoothstep(0.0, 1.0, 0.5), 0.5);
+        assert_eq!(smoothstep(0.0, 1.0, 1.0), 1.0);
+        assert_eq!(smoothstep(2.0, 4.0, 3.0), 0.5);
+        assert_eq!(smoothstep(2.0, 4.0, -10.0), 0.0);
+        assert_eq!(smoothstep(2.0, 4.0, 10.0), 1.0);
+    }
+
+    #[test]
+    fn smootherstep() {
+        use super::smootherstep;
+
+        assert_eq!(smootherstep(0.0, 1.0, 0.0), 0.0);
+        assert_eq!(smootherstep(0.0, 1.0, 0.5), 0.5);
+        assert_eq!(smootherstep(0.0, 1.0, 1.0), 1.0);
+        assert_eq!(smootherstep(2.0, 4.0, -10.0), 0.0);
+        assert_eq!(smootherstep(2.0, 4.0, 10.0), 1.0);
+    }
+
+    #[test]
+    fn easing_boundaries() {
+        use super::Easing;
+
+        let all = [
+            Easing::Linear,
+            Easing::SineIn,
+            Easing::SineOut,
+            Easing::SineInOut,
+            Easing::QuadIn,
+            Easing::QuadOut,
+            Easing::QuadInOut,
+            Easing::CubicIn,
+            Easing::CubicOut,
+            Easing::CubicInOut,
+            Easing::QuartIn,
+            Easing::QuartOut,
+            Easing::QuartInOut,
+            Easing::QuintIn,
+            Easing::QuintOut,
+            Easing::QuintInOut,
+            Easing::SextIn,
+            Easing::SextOut,
+            Easing::SextInOut,
+            Easing::SeptIn,
+            Easing::SeptOut,
+            Easing::SeptInOut,
+            Easing::OctIn,
+            Easing::OctOut,
+            Easing::OctInOut,
+            Easing::ExpoIn,
+            Easing::ExpoOut,
+            Easing::ExpoInOut,
+            Easing::CircIn,
+            Easing::CircOut,
+            Easing::CircInOut,
+            Easing::BackIn,
+            Easing::BackOut,
+            Easing::BackInOut,
+            Easing::ElasticIn,
+            Easing::ElasticOut,
+            Easing::ElasticInOut,
+            Easing::BounceIn,
+            Easing::BounceOut,
+            Easing::BounceInOut,
+        ];
+
+        for easing in all {
+            assert!(easing.apply(0.0_f64).abs() < 1e-9, "{:?} at 0", easing);
+            assert!((easing.apply(1.0_f64) - 1.0).abs() < 1e-9, "{:?} at 1", easing);
+        }
+    }
+
+    #[test]
+    fn elastic_and_bounce_boundaries() {
+        use super::Ease;
+
+        for f in [Ease::elastic_out, Ease::elastic_inout, Ease::bounce_in, Ease::bounce_out, Ease::bounce_inout] {
+            assert_eq!(f(&Ease, 0.0_f64), 0.0);
+            assert_eq!(f(&Ease, 1.0_f64), 1.0);
+        }
+    }
+
+    #[test]
+    fn bounce_out_samples() {
+        use super::Ease;
+
+        let samples = [0.0, 0.1, 0.2, 0.3, 0.35];
+        let values = samples.map(|t: f64| Ease.bounce_out(t));
+        assert!(values.windows(2).all(|w| w[0] < w[1]));
+
+        for t in [0.4, 0.5, 0.6, 0.75, 0.8, 0.95] {
+            let v = Ease.bounce_out(t);
+            assert!((0.0..=1.0).contains(&v), "bounce_out({}) = {}", t, v);
+        }
+
+        assert!((Ease.bounce_out(0.5_f64) - 0.765625).abs() < 1e-12);
+    }
+
     fn ease_linear() {
         use super::Ease;
         
@@ -323,4 +663,23 @@ mod tests {
         assert_eq!(Ease.linear(0.25), 0.25);
         assert_eq!(Ease.linear(0.75), 0.75);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn apply_between_scalars_and_vectors() {
+        use super::{Easing, Lerp};
+        use crate::vectors::{Vector2, Vector3};
+
+        assert_eq!(Easing::Linear.apply_between(2.0f32, 4.0, 0.5), 3.0);
+        assert_eq!(Easing::QuadIn.apply_between(0.0f32, 8.0, 0.5), 2.0);
+        assert_eq!(Lerp::lerp(1.0f64, 3.0, 0.25), 1.5);
+
+        let from = Vector3::<f32>::new_comp(0.0, 0.0, 0.0);
+        let to = Vector3::new_comp(8.0, -4.0, 2.0);
+        assert_eq!(Easing::Linear.apply_between(from, to, 0.5), Vector3::new_comp(4.0, -2.0, 1.0));
+        assert_eq!(Easing::QuadIn.apply_between(from, to, 0.5), Vector3::new_comp(2.0, -1.0, 0.5));
+        assert_eq!(Easing::BounceOut.apply_between(from, to, 1.0), to);
+
+        let eased = Easing::QuadIn.apply_between(Vector2::new_comp(0.0, 0.0), Vector2::new_comp(10.0, 0.0), 0.5);
+        assert_eq!(eased, Vector2::new_comp(2.5, 0.0));
+    }
+}
@@ -1,6 +1,9 @@
 #![allow(dead_code)]
 
 pub mod vectors;
+pub mod matrices;
 pub mod shapes;
 pub mod interpolate;
-pub mod traits;
\ No newline at end of file
+pub mod math;
+pub mod traits;
+pub mod prelude;
\ No newline at end of file
@@ -0,0 +1,25 @@
+use num_traits::real::Real;
+
+#[inline]
+pub fn to_degrees<T>(radians: T) -> T
+where T: Real {
+    radians.to_degrees()
+}
+
+#[inline]
+pub fn to_radians<T>(degrees: T) -> T
+where T: Real {
+    degrees.to_radians()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn degree_radian_conversions() {
+        assert_eq!(to_degrees(std::f64::consts::PI), 180.0);
+        assert_eq!(to_radians(180.0), std::f64::consts::PI);
+        assert_eq!(to_degrees(to_radians(45.0f32)), 45.0);
+    }
+}
@@ -0,0 +1,287 @@
+use std::ops::{Add, Mul};
+use num_traits::{real::Real, Zero, One};
+
+use crate::vectors::{Vector2, Vector3, Vector4};
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Matrix2<T> {
+    pub columns: [Vector2<T>; 2],
+}
+
+impl<T> Matrix2<T> {
+    #[inline]
+    pub fn from_columns(x: Vector2<T>, y: Vector2<T>) -> Self {
+        Self { columns: [x, y] }
+    }
+
+    #[inline]
+    pub fn identity() -> Self
+    where T: Zero + One {
+        Self::from_scale(Vector2::new_comp(T::one(), T::one()))
+    }
+
+    #[inline]
+    pub fn from_scale(scale: Vector2<T>) -> Self
+    where T: Zero {
+        Self::from_columns(
+            Vector2::new_comp(scale.x, T::zero()),
+            Vector2::new_comp(T::zero(), scale.y))
+    }
+
+    #[inline]
+    pub fn from_rotation(radians: T) -> Self
+    where T: Real {
+        let (sin, cos) = radians.sin_cos();
+        Self::from_columns(
+            Vector2::new_comp(cos, sin),
+            Vector2::new_comp(-sin, cos))
+    }
+}
+
+impl<T> Mul<Vector2<T>> for Matrix2<T>
+where T: Mul<Output = T> + Add<Output = T> + Copy {
+    type Output = Vector2<T>;
+
+    #[inline]
+    fn mul(self, rhs: Vector2<T>) -> Self::Output {
+        self.columns[0] * rhs.x + self.columns[1] * rhs.y
+    }
+}
+
+impl<T> Mul<Matrix2<T>> for Matrix2<T>
+where T: Mul<Output = T> + Add<Output = T> + Copy {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Matrix2<T>) -> Self::Output {
+        Self { columns: rhs.columns.map(|column| self * column) }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Matrix3<T> {
+    pub columns: [Vector3<T>; 3],
+}
+
+impl<T> Matrix3<T> {
+    #[inline]
+    pub fn from_columns(x: Vector3<T>, y: Vector3<T>, z: Vector3<T>) -> Self {
+        Self { columns: [x, y, z] }
+    }
+
+    #[inline]
+    pub fn identity() -> Self
+    where T: Zero + One {
+        Self::from_scale(Vector2::new_comp(T::one(), T::one()))
+    }
+
+    #[inline]
+    pub fn from_translation(translation: Vector2<T>) -> Self
+    where T: Zero + One {
+        Self::from_columns(
+            Vector3::new_comp(T::one(), T::zero(), T::zero()),
+            Vector3::new_comp(T::zero(), T::one(), T::zero()),
+            Vector3::new_comp(translation.x, translation.y, T::one()))
+    }
+
+    #[inline]
+    pub fn from_scale(scale: Vector2<T>) -> Self
+    where T: Zero + One {
+        Self::from_columns(
+            Vector3::new_comp(scale.x, T::zero(), T::zero()),
+            Vector3::new_comp(T::zero(), scale.y, T::zero()),
+            Vector3::new_comp(T::zero(), T::zero(), T::one()))
+    }
+
+    #[inline]
+    pub fn from_rotation(radians: T) -> Self
+    where T: Real {
+        let (sin, cos) = radians.sin_cos();
+        Self::from_columns(
+            Vector3::new_comp(cos, sin, T::zero()),
+            Vector3::new_comp(-sin, cos, T::zero()),
+            Vector3::new_comp(T::zero(), T::zero(), T::one()))
+    }
+
+    /// Linear 3D rotation of `radians` around `axis`, which is normalized here. A zero-length
+    /// axis gives the identity. Unlike the other constructors this treats the matrix as a 3D
+    /// transform rather than a 2D affine one.
+    #[inline]
+    pub fn from_axis_angle(axis: Vector3<T>, radians: T) -> Self
+    where T: Real {
+        let Some(axis) = axis.try_normalized() else {
+            return Self::identity();
+        };
+
+        let (x, y, z) = (axis.x, axis.y, axis.z);
+        let (sin, cos) = radians.sin_cos();
+        let t = T::one() - cos;
+
+        Self::from_columns(
+            Vector3::new_comp(t * x * x + cos, t * x * y + sin * z, t * x * z - sin * y),
+            Vector3::new_comp(t * x * y - sin * z, t * y * y + cos, t * y * z + sin * x),
+            Vector3::new_comp(t * x * z + sin * y, t * y * z - sin * x, t * z * z + cos))
+    }
+}
+
+impl<T> Mul<Vector3<T>> for Matrix3<T>
+where T: Mul<Output = T> + Add<Output = T> + Copy {
+    type Output = Vector3<T>;
+
+    #[inline]
+    fn mul(self, rhs: Vector3<T>) -> Self::Output {
+        self.columns[0] * rhs.x + self.columns[1] * rhs.y + self.columns[2] * rhs.z
+    }
+}
+
+impl<T> Mul<Matrix3<T>> for Matrix3<T>
+where T: Mul<Output = T> + Add<Output = T> + Copy {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Matrix3<T>) -> Self::Output {
+        Self { columns: rhs.columns.map(|column| self * column) }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Matrix4<T> {
+    pub columns: [Vector4<T>; 4],
+}
+
+impl<T> Matrix4<T> {
+    #[inline]
+    pub fn from_columns(x: Vector4<T>, y: Vector4<T>, z: Vector4<T>, w: Vector4<T>) -> Self {
+        Self { columns: [x, y, z, w] }
+    }
+
+    #[inline]
+    pub fn identity() -> Self
+    where T: Zero + One {
+        Self::from_scale(Vector3::new_comp(T::one(), T::one(), T::one()))
+    }
+
+    #[inline]
+    pub fn from_translation(translation: Vector3<T>) -> Self
+    where T: Zero + One {
+        Self::from_columns(
+            Vector4::new_comp(T::one(), T::zero(), T::zero(), T::zero()),
+            Vector4::new_comp(T::zero(), T::one(), T::zero(), T::zero()),
+            Vector4::new_comp(T::zero(), T::zero(), T::one(), T::zero()),
+            Vector4::new_comp(translation.x, translation.y, translation.z, T::one()))
+    }
+
+    #[inline]
+    pub fn from_scale(scale: Vector3<T>) -> Self
+    where T: Zero + One {
+        Self::from_columns(
+            Vector4::new_comp(scale.x, T::zero(), T::zero(), T::zero()),
+            Vector4::new_comp(T::zero(), scale.y, T::zero(), T::zero()),
+            Vector4::new_comp(T::zero(), T::zero(), scale.z, T::zero()),
+            Vector4::new_comp(T::zero(), T::zero(), T::zero(), T::one()))
+    }
+
+    /// Right-handed rotation of `radians` around `axis`, which is normalized here. A zero-length
+    /// axis gives the identity.
+    #[inline]
+    pub fn from_rotation(axis: Vector3<T>, radians: T) -> Self
+    where T: Real {
+        let [x, y, z] = Matrix3::from_axis_angle(axis, radians).columns;
+
+        Self::from_columns(
+            Vector4::new_comp(x.x, x.y, x.z, T::zero()),
+            Vector4::new_comp(y.x, y.y, y.z, T::zero()),
+            Vector4::new_comp(z.x, z.y, z.z, T::zero()),
+            Vector4::new_comp(T::zero(), T::zero(), T::zero(), T::one()))
+    }
+}
+
+impl<T> Mul<Vector4<T>> for Matrix4<T>
+where T: Mul<Output = T> + Add<Output = T> + Copy {
+    type Output = Vector4<T>;
+
+    #[inline]
+    fn mul(self, rhs: Vector4<T>) -> Self::Output {
+        self.columns[0] * rhs.x + self.columns[1] * rhs.y + self.columns[2] * rhs.z + self.columns[3] * rhs.w
+    }
+}
+
+impl<T> Mul<Matrix4<T>> for Matrix4<T>
+where T: Mul<Output = T> + Add<Output = T> + Copy {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Matrix4<T>) -> Self::Output {
+        Self { columns: rhs.columns.map(|column| self * column) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matrix2_rotation_and_scale() {
+        let rotation = Matrix2::from_rotation(std::f64::consts::FRAC_PI_2);
+        let rotated = rotation * Vector2::new_comp(1.0, 0.0);
+        assert!(Vector2::distance(rotated, Vector2::new_comp(0.0, 1.0)) < 1e-12);
+
+        let scale = Matrix2::from_scale(Vector2::new_comp(2.0, 3.0));
+        assert_eq!(scale * Vector2::new_comp(1.0, 1.0), Vector2::new_comp(2.0, 3.0));
+        assert_eq!(Matrix2::identity() * scale, scale);
+    }
+
+    #[test]
+    fn matrix3_translate_and_scale() {
+        let translation = Matrix3::from_translation(Vector2::new_comp(5.0, -1.0));
+        let scale = Matrix3::from_scale(Vector2::new_comp(2.0, 3.0));
+        let point = Vector3::new_comp(1.0, 2.0, 1.0);
+
+        assert_eq!((translation * scale) * point, Vector3::new_comp(7.0, 5.0, 1.0));
+        assert_eq!((scale * translation) * point, Vector3::new_comp(12.0, 3.0, 1.0));
+        assert_eq!(translation * Vector3::new_comp(1.0, 2.0, 0.0), Vector3::new_comp(1.0, 2.0, 0.0));
+        assert_eq!(Matrix3::identity() * point, point);
+    }
+
+    #[test]
+    fn matrix3_rotation() {
+        let rotation = Matrix3::from_rotation(std::f64::consts::FRAC_PI_2);
+        let rotated = rotation * Vector3::new_comp(1.0, 0.0, 1.0);
+        assert!(Vector3::distance(rotated, Vector3::new_comp(0.0, 1.0, 1.0)) < 1e-12);
+    }
+
+    #[test]
+    fn matrix3_axis_angle() {
+        let rotation = Matrix3::from_axis_angle(Vector3::new_comp(3.0, 0.0, 0.0), std::f64::consts::FRAC_PI_2);
+        let rotated = rotation * Vector3::new_comp(0.0, 1.0, 0.0);
+        assert!(Vector3::distance(rotated, Vector3::new_comp(0.0, 0.0, 1.0)) < 1e-12);
+
+        let rotation = Matrix3::from_axis_angle(Vector3::new_comp(0.0, 0.0, 1.0), 0.3);
+        let rotated = rotation * Vector3::new_comp(1.0, 2.0, 0.0);
+        assert!(Vector3::distance(rotated, Matrix3::from_rotation(0.3) * Vector3::new_comp(1.0, 2.0, 0.0)) < 1e-12);
+
+        assert_eq!(Matrix3::from_axis_angle(Vector3::new_comp(0.0, 0.0, 0.0), 1.0), Matrix3::identity());
+        assert_eq!(Matrix4::from_rotation(Vector3::new_comp(0.0, 0.0, 0.0), 1.0), Matrix4::identity());
+    }
+
+    #[test]
+    fn matrix4_translate_and_scale() {
+        let translation = Matrix4::from_translation(Vector3::new_comp(1.0, 2.0, 3.0));
+        let scale = Matrix4::from_scale(Vector3::new_comp(2.0, 2.0, 2.0));
+        let point = Vector4::new_comp(1.0, 1.0, 1.0, 1.0);
+
+        assert_eq!((translation * scale) * point, Vector4::new_comp(3.0, 4.0, 5.0, 1.0));
+        assert_eq!(Matrix4::identity() * translation, translation);
+    }
+
+    #[test]
+    fn matrix4_rotation() {
+        let rotation = Matrix4::from_rotation(Vector3::new_comp(0.0, 0.0, 2.0), std::f64::consts::FRAC_PI_2);
+        let rotated = rotation * Vector4::new_comp(1.0, 0.0, 0.0, 1.0);
+        assert!(Vector4::distance(rotated, Vector4::new_comp(0.0, 1.0, 0.0, 1.0)) < 1e-12);
+
+        let rotation = Matrix4::from_rotation(Vector3::new_comp(1.0, 1.0, 1.0), 2.0 * std::f64::consts::FRAC_PI_3);
+        let rotated = rotation * Vector4::new_comp(1.0, 0.0, 0.0, 0.0);
+        assert!(Vector4::distance(rotated, Vector4::new_comp(0.0, 1.0, 0.0, 0.0)) < 1e-12);
+    }
+}
@@ -0,0 +1,36 @@
+//! Re-exports the vectors, shapes and traits needed for typical use.
+//!
+//! ```
+//! use geo::prelude::*;
+//!
+//! let circle = Circle::new(0.0, 0.0, 1.0);
+//! let rect = Rect::new(0.5, -0.5, 1.0, 1.0);
+//!
+//! assert!(rect.overlaps_circle(circle));
+//! assert!(circle.contains(Vector2::new_comp(0.5, 0.0)));
+//! assert_eq!(circle.get_circumference(), 2.0 * f64::pi());
+//! assert_eq!(Ease.quad_in(0.5), 0.25);
+//! ```
+//!
+//! The numeric aliases are included as well:
+//!
+//! ```
+//! use geo::prelude::*;
+//!
+//! let position: Vector3f32 = Vector3::new_comp(1.0, 2.0, 3.0);
+//! let sphere = Sphere::new_vector(position, 2.0);
+//! assert!(sphere.contains(Vector3f32::new_comp(1.0, 2.0, 4.5)));
+//! assert_eq!(Vector2i32::new_comp(1, 2).x, 1);
+//! ```
+
+pub use crate::vectors::*;
+pub use crate::matrices::{Matrix2, Matrix3, Matrix4};
+pub use crate::shapes::{
+    Aabb, AabbN, Orientation, orientation, convex_hull,
+    Rect, Area2D, Bounds2D, Circle, CircleIntersection, Line2D, Polygon2D, Ray2D, Capsule2D, triangle_area,
+    Cube, Area3D, Bounds3D, Sphere, Line3D, Ray3D, Plane, Capsule3D, Triangle3D, triangle_area3,
+    Area4D, Bounds4D, HyperSphere, Line4D,
+};
+pub use crate::traits::Pi;
+pub use crate::math::{to_degrees, to_radians};
+pub use crate::interpolate::{Ease, Easing, Lerp, interpolate, smoothstep, smootherstep};
@@ -0,0 +1,413 @@
+use std::ops::{Add, Sub};
+
+use num_traits::real::Real;
+
+use crate::vectors::{Vector2, Vector3};
+
+use super::{Rect, Area2D, Cube, Area3D, Area4D};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AabbN<T, const N: usize> {
+    pub min: [T; N],
+    pub max: [T; N],
+}
+
+impl<T, const N: usize> AabbN<T, N> {
+    #[inline]
+    pub fn new(min: [T; N], max: [T; N]) -> Self {
+        Self { min, max }
+    }
+
+    #[inline]
+    pub fn contains(&self, point: [T; N]) -> bool
+    where T: PartialOrd {
+        (0..N).all(|i| point[i] >= self.min[i] && point[i] <= self.max[i])
+    }
+
+    #[inline]
+    pub fn overlaps(&self, other: &AabbN<T, N>) -> bool
+    where T: PartialOrd {
+        (0..N).all(|i| self.min[i] < other.max[i] && self.max[i] > other.min[i])
+    }
+
+    #[inline]
+    pub fn union(&self, other: &AabbN<T, N>) -> Self
+    where T: PartialOrd + Copy {
+        let mut min = self.min;
+        let mut max = self.max;
+
+        for i in 0..N {
+            if other.min[i] < min[i] {
+                min[i] = other.min[i];
+            }
+
+            if other.max[i] > max[i] {
+                max[i] = other.max[i];
+            }
+        }
+
+        Self { min, max }
+    }
+
+    /// Returns `None` when the boxes are separated on any axis. Boxes that only touch
+    /// produce a zero-sized intersection.
+    #[inline]
+    pub fn intersection(&self, other: &AabbN<T, N>) -> Option<Self>
+    where T: PartialOrd + Copy {
+        let mut min = self.min;
+        let mut max = self.max;
+
+        for i in 0..N {
+            if other.min[i] > min[i] {
+                min[i] = other.min[i];
+            }
+
+            if other.max[i] < max[i] {
+                max[i] = other.max[i];
+            }
+
+            if min[i] > max[i] {
+                return None;
+            }
+        }
+
+        Some(Self { min, max })
+    }
+}
+
+#[inline]
+fn ordered<T>(a: T, b: T) -> (T, T)
+where T: PartialOrd {
+    if b < a { (b, a) } else { (a, b) }
+}
+
+impl<T> From<Rect<T>> for AabbN<T, 2>
+where T: PartialOrd + Add<Output = T> + Copy {
+    #[inline]
+    fn from(rect: Rect<T>) -> Self {
+        let (x_min, x_max) = ordered(rect.get_x_min(), rect.get_x_max());
+        let (y_min, y_max) = ordered(rect.get_y_min(), rect.get_y_max());
+        Self::new([x_min, y_min], [x_max, y_max])
+    }
+}
+
+impl<T> From<Area2D<T>> for AabbN<T, 2> {
+    #[inline]
+    fn from(area: Area2D<T>) -> Self {
+        Self::new([area.lower_left.x, area.lower_left.y], [area.upper_right.x, area.upper_right.y])
+    }
+}
+
+impl<T> From<Cube<T>> for AabbN<T, 3>
+where T: PartialOrd + Add<Output = T> + Copy {
+    #[inline]
+    fn from(cube: Cube<T>) -> Self {
+        let (x_min, x_max) = ordered(cube.get_x_min(), cube.get_x_max());
+        let (y_min, y_max) = ordered(cube.get_y_min(), cube.get_y_max());
+        let (z_min, z_max) = ordered(cube.get_z_min(), cube.get_z_max());
+        Self::new([x_min, y_min, z_min], [x_max, y_max, z_max])
+    }
+}
+
+impl<T> From<Area3D<T>> for AabbN<T, 3>
+where T: Copy {
+    #[inline]
+    fn from(area: Area3D<T>) -> Self {
+        Self::new(area.lower_left.into(), area.upper_right.into())
+    }
+}
+
+impl<T> From<Area4D<T>> for AabbN<T, 4>
+where T: Copy {
+    #[inline]
+    fn from(area: Area4D<T>) -> Self {
+        Self::new(area.lower_left.into(), area.upper_right.into())
+    }
+}
+
+impl<T> From<AabbN<T, 2>> for Rect<T>
+where T: Sub<Output = T> + Copy {
+    #[inline]
+    fn from(aabb: AabbN<T, 2>) -> Self {
+        Rect::new(aabb.min[0], aabb.min[1], aabb.max[0] - aabb.min[0], aabb.max[1] - aabb.min[1])
+    }
+}
+
+impl<T> From<AabbN<T, 3>> for Cube<T>
+where T: Sub<Output = T> + Copy {
+    #[inline]
+    fn from(aabb: AabbN<T, 3>) -> Self {
+        Cube::new(
+            aabb.min[0], aabb.min[1], aabb.min[2],
+            aabb.max[0] - aabb.min[0], aabb.max[1] - aabb.min[1], aabb.max[2] - aabb.min[2])
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Aabb<V> {
+    pub min: V,
+    pub max: V,
+}
+
+impl<V> Aabb<V> {
+    #[inline]
+    pub fn new(min: V, max: V) -> Self {
+        Self { min, max }
+    }
+}
+
+impl<T> Aabb<Vector2<T>> {
+    #[inline]
+    pub fn center(&self) -> Vector2<T>
+    where T: Real {
+        (self.min + self.max) / (T::one() + T::one())
+    }
+
+    #[inline]
+    pub fn size(&self) -> Vector2<T>
+    where T: Sub<Output = T> + Copy {
+        self.max - self.min
+    }
+
+    #[inline]
+    pub fn contains(&self, point: Vector2<T>) -> bool
+    where T: PartialOrd + Copy {
+        AabbN::from(*self).contains(point.into())
+    }
+
+    #[inline]
+    pub fn overlaps(&self, other: &Aabb<Vector2<T>>) -> bool
+    where T: PartialOrd + Copy {
+        AabbN::from(*self).overlaps(&AabbN::from(*other))
+    }
+
+    #[inline]
+    pub fn union(&self, other: &Aabb<Vector2<T>>) -> Self
+    where T: PartialOrd + Copy {
+        Self::new(Vector2::min(self.min, other.min), Vector2::max(self.max, other.max))
+    }
+
+    #[inline]
+    pub fn intersection(&self, other: &Aabb<Vector2<T>>) -> Option<Self>
+    where T: PartialOrd + Copy {
+        AabbN::from(*self).intersection(&AabbN::from(*other)).map(Self::from)
+    }
+
+    #[inline]
+    pub fn expand_to_include(&mut self, point: Vector2<T>)
+    where T: PartialOrd + Copy {
+        self.min = Vector2::min(self.min, point);
+        self.max = Vector2::max(self.max, point);
+    }
+}
+
+impl<T> From<Aabb<Vector2<T>>> for AabbN<T, 2>
+where T: Copy {
+    #[inline]
+    fn from(aabb: Aabb<Vector2<T>>) -> Self {
+        Self::new(aabb.min.into(), aabb.max.into())
+    }
+}
+
+impl<T> From<AabbN<T, 2>> for Aabb<Vector2<T>>
+where T: Copy {
+    #[inline]
+    fn from(aabb: AabbN<T, 2>) -> Self {
+        Self::new(aabb.min.into(), aabb.max.into())
+    }
+}
+
+impl<T> From<Rect<T>> for Aabb<Vector2<T>>
+where T: PartialOrd + Add<Output = T> + Copy {
+    #[inline]
+    fn from(shape: Rect<T>) -> Self {
+        Self::from(AabbN::from(shape))
+    }
+}
+
+impl<T> From<Aabb<Vector2<T>>> for Rect<T>
+where T: Sub<Output = T> + Copy {
+    #[inline]
+    fn from(aabb: Aabb<Vector2<T>>) -> Self {
+        Rect::new_vectors(aabb.min, aabb.max - aabb.min)
+    }
+}
+
+impl<T> Aabb<Vector3<T>> {
+    #[inline]
+    pub fn center(&self) -> Vector3<T>
+    where T: Real {
+        (self.min + self.max) / (T::one() + T::one())
+    }
+
+    #[inline]
+    pub fn size(&self) -> Vector3<T>
+    where T: Sub<Output = T> + Copy {
+        self.max - self.min
+    }
+
+    #[inline]
+    pub fn contains(&self, point: Vector3<T>) -> bool
+    where T: PartialOrd + Copy {
+        AabbN::from(*self).contains(point.into())
+    }
+
+    #[inline]
+    pub fn overlaps(&self, other: &Aabb<Vector3<T>>) -> bool
+    where T: PartialOrd + Copy {
+        AabbN::from(*self).overlaps(&AabbN::from(*other))
+    }
+
+    #[inline]
+    pub fn union(&self, other: &Aabb<Vector3<T>>) -> Self
+    where T: PartialOrd + Copy {
+        Self::new(Vector3::min(self.min, other.min), Vector3::max(self.max, other.max))
+    }
+
+    #[inline]
+    pub fn intersection(&self, other: &Aabb<Vector3<T>>) -> Option<Self>
+    where T: PartialOrd + Copy {
+        AabbN::from(*self).intersection(&AabbN::from(*other)).map(Self::from)
+    }
+
+    #[inline]
+    pub fn expand_to_include(&mut self, point: Vector3<T>)
+    where T: PartialOrd + Copy {
+        self.min = Vector3::min(self.min, point);
+        self.max = Vector3::max(self.max, point);
+    }
+}
+
+impl<T> From<Aabb<Vector3<T>>> for AabbN<T, 3>
+where T: Copy {
+    #[inline]
+    fn from(aabb: Aabb<Vector3<T>>) -> Self {
+        Self::new(aabb.min.into(), aabb.max.into())
+    }
+}
+
+impl<T> From<AabbN<T, 3>> for Aabb<Vector3<T>>
+where T: Copy {
+    #[inline]
+    fn from(aabb: AabbN<T, 3>) -> Self {
+        Self::new(aabb.min.into(), aabb.max.into())
+    }
+}
+
+impl<T> From<Cube<T>> for Aabb<Vector3<T>>
+where T: PartialOrd + Add<Output = T> + Copy {
+    #[inline]
+    fn from(shape: Cube<T>) -> Self {
+        Self::from(AabbN::from(shape))
+    }
+}
+
+impl<T> From<Aabb<Vector3<T>>> for Cube<T>
+where T: Sub<Output = T> + Copy {
+    #[inline]
+    fn from(aabb: Aabb<Vector3<T>>) -> Self {
+        Cube::new_vectors(aabb.min, aabb.max - aabb.min)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aabb2_contains_and_overlaps() {
+        let aabb = AabbN::new([0.0, 0.0], [2.0, 2.0]);
+        assert!(aabb.contains([1.0, 1.0]));
+        assert!(aabb.contains([2.0, 2.0]));
+        assert!(!aabb.contains([2.5, 1.0]));
+
+        assert!(aabb.overlaps(&AabbN::new([1.0, 1.0], [3.0, 3.0])));
+        assert!(!aabb.overlaps(&AabbN::new([2.0, 0.0], [3.0, 2.0])));
+    }
+
+    #[test]
+    fn aabb3_contains_and_overlaps() {
+        let aabb = AabbN::new([0, 0, 0], [4, 4, 4]);
+        assert!(aabb.contains([4, 0, 2]));
+        assert!(!aabb.contains([1, 5, 1]));
+
+        assert!(aabb.overlaps(&AabbN::new([3, 3, 3], [5, 5, 5])));
+        assert!(!aabb.overlaps(&AabbN::new([3, 3, 5], [5, 5, 6])));
+    }
+
+    #[test]
+    fn aabb4_contains_and_overlaps() {
+        let aabb = AabbN::new([0.0; 4], [1.0; 4]);
+        assert!(aabb.contains([0.5, 0.5, 0.5, 0.5]));
+        assert!(!aabb.contains([0.5, 0.5, 0.5, 1.5]));
+
+        assert!(aabb.overlaps(&AabbN::new([0.5; 4], [2.0; 4])));
+        assert!(!aabb.overlaps(&AabbN::new([0.5, 0.5, 0.5, 1.5], [2.0; 4])));
+    }
+
+    #[test]
+    fn aabb5_contains_and_overlaps() {
+        let aabb = AabbN::new([-1.0; 5], [1.0; 5]);
+        assert!(aabb.contains([0.0; 5]));
+        assert!(!aabb.contains([0.0, 0.0, 0.0, 0.0, -2.0]));
+
+        assert!(aabb.overlaps(&AabbN::new([0.0; 5], [3.0; 5])));
+        assert!(!aabb.overlaps(&AabbN::new([2.0; 5], [3.0; 5])));
+    }
+
+    #[test]
+    fn aabb_union_and_intersection() {
+        let a = AabbN::new([0.0, 0.0, 0.0], [2.0, 2.0, 2.0]);
+        let b = AabbN::new([1.0, -1.0, 1.0], [3.0, 1.0, 4.0]);
+
+        assert_eq!(a.union(&b), AabbN::new([0.0, -1.0, 0.0], [3.0, 2.0, 4.0]));
+        assert_eq!(a.intersection(&b), Some(AabbN::new([1.0, 0.0, 1.0], [2.0, 1.0, 2.0])));
+
+        let c = AabbN::new([5.0, 5.0, 5.0], [6.0, 6.0, 6.0]);
+        assert_eq!(a.intersection(&c), None);
+    }
+
+    #[test]
+    fn shapes_delegate_to_aabb() {
+        let rect = Rect::new(0.0, 0.0, 2.0, 1.0);
+        assert_eq!(Rect::from(AabbN::from(rect)), rect);
+    }
+
+    #[test]
+    fn aabb_expand_to_include() {
+        let mut aabb = Aabb::new(Vector2::new_comp(0.0, 0.0), Vector2::new_comp(1.0, 1.0));
+        aabb.expand_to_include(Vector2::new_comp(3.0, 0.5));
+        assert_eq!(aabb, Aabb::new(Vector2::new_comp(0.0, 0.0), Vector2::new_comp(3.0, 1.0)));
+
+        aabb.expand_to_include(Vector2::new_comp(-1.0, -2.0));
+        assert_eq!(aabb, Aabb::new(Vector2::new_comp(-1.0, -2.0), Vector2::new_comp(3.0, 1.0)));
+
+        aabb.expand_to_include(Vector2::new_comp(0.0, 0.0));
+        assert_eq!(aabb.size(), Vector2::new_comp(4.0, 3.0));
+        assert_eq!(aabb.center(), Vector2::new_comp(1.0, -0.5));
+
+        let mut aabb = Aabb::new(Vector3::new_comp(0, 0, 0), Vector3::new_comp(0, 0, 0));
+        for point in [Vector3::new_comp(1, -1, 2), Vector3::new_comp(-3, 4, 0)] {
+            aabb.expand_to_include(point);
+            assert!(aabb.contains(point));
+        }
+        assert_eq!(aabb, Aabb::new(Vector3::new_comp(-3, -1, 0), Vector3::new_comp(1, 4, 2)));
+    }
+
+    #[test]
+    fn aabb_generic_queries() {
+        let a = Aabb::new(Vector3::new_comp(0.0, 0.0, 0.0), Vector3::new_comp(2.0, 2.0, 2.0));
+        let b = Aabb::new(Vector3::new_comp(1.0, 1.0, 1.0), Vector3::new_comp(3.0, 3.0, 3.0));
+        assert!(a.overlaps(&b));
+        assert_eq!(a.union(&b), Aabb::new(Vector3::new_comp(0.0, 0.0, 0.0), Vector3::new_comp(3.0, 3.0, 3.0)));
+        assert_eq!(a.intersection(&b), Some(Aabb::new(Vector3::new_comp(1.0, 1.0, 1.0), Vector3::new_comp(2.0, 2.0, 2.0))));
+
+        let rect = Rect::new(1.0, 2.0, 3.0, 4.0);
+        let aabb = Aabb::from(rect);
+        assert_eq!(aabb, Aabb::new(Vector2::new_comp(1.0, 2.0), Vector2::new_comp(4.0, 6.0)));
+        assert_eq!(Rect::from(aabb), rect);
+
+        let cube = Cube::new(1.0, 2.0, 3.0, 1.0, 1.0, 1.0);
+        assert_eq!(Cube::from(Aabb::from(cube)), cube);
+    }
+}
@@ -0,0 +1,144 @@
+use num_traits::real::Real;
+
+use crate::vectors::{Vector2, Vector3};
+
+use super::{Circle, Line2D, Line3D, Sphere};
+
+/// Every point within `radius` of the segment from `a` to `b`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Capsule2D<T> {
+    pub a: Vector2<T>,
+    pub b: Vector2<T>,
+    pub radius: T,
+}
+
+impl<T> Capsule2D<T> {
+    #[inline]
+    pub fn new(a: Vector2<T>, b: Vector2<T>, radius: T) -> Self {
+        Self { a, b, radius }
+    }
+
+    #[inline]
+    pub fn segment(&self) -> Line2D<T>
+    where T: Copy {
+        Line2D::new_vectors(self.a, self.b)
+    }
+
+    #[inline]
+    pub fn contains(&self, point: Vector2<T>) -> bool
+    where T: Real {
+        self.segment().distance_to_point(point) <= self.radius
+    }
+
+    #[inline]
+    pub fn overlaps_circle(&self, circle: &Circle<T>) -> bool
+    where T: Real {
+        self.segment().distance_to_point(circle.center) <= self.radius + circle.radius
+    }
+
+    #[inline]
+    pub fn overlaps(&self, other: &Capsule2D<T>) -> bool
+    where T: Real {
+        self.segment().distance_to_segment(&other.segment()) <= self.radius + other.radius
+    }
+}
+
+/// Every point within `radius` of the segment from `a` to `b`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Capsule3D<T> {
+    pub a: Vector3<T>,
+    pub b: Vector3<T>,
+    pub radius: T,
+}
+
+impl<T> Capsule3D<T> {
+    #[inline]
+    pub fn new(a: Vector3<T>, b: Vector3<T>, radius: T) -> Self {
+        Self { a, b, radius }
+    }
+
+    #[inline]
+    pub fn segment(&self) -> Line3D<T>
+    where T: Copy {
+        Line3D::new_vectors(self.a, self.b)
+    }
+
+    #[inline]
+    pub fn contains(&self, point: Vector3<T>) -> bool
+    where T: Real {
+        self.segment().distance_to_point(point) <= self.radius
+    }
+
+    #[inline]
+    pub fn overlaps_sphere(&self, sphere: &Sphere<T>) -> bool
+    where T: Real {
+        self.segment().distance_to_point(sphere.center) <= self.radius + sphere.radius
+    }
+
+    #[inline]
+    pub fn overlaps(&self, other: &Capsule3D<T>) -> bool
+    where T: Real {
+        self.segment().distance_to_segment(&other.segment()) <= self.radius + other.radius
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capsule2d_contains_body_and_caps() {
+        let capsule = Capsule2D::new(Vector2::new_comp(0.0, 0.0), Vector2::new_comp(4.0, 0.0), 1.0);
+
+        assert!(capsule.contains(Vector2::new_comp(2.0, 0.9)));
+        assert!(capsule.contains(Vector2::new_comp(2.0, -1.0)));
+        assert!(!capsule.contains(Vector2::new_comp(2.0, 1.1)));
+
+        // near the caps only the rounded end counts, not the square corner
+        assert!(capsule.contains(Vector2::new_comp(-0.9, 0.0)));
+        assert!(capsule.contains(Vector2::new_comp(4.6, 0.6)));
+        assert!(!capsule.contains(Vector2::new_comp(4.9, 0.9)));
+        assert!(!capsule.contains(Vector2::new_comp(-0.9, -0.9)));
+    }
+
+    #[test]
+    fn capsule2d_overlaps() {
+        let capsule = Capsule2D::new(Vector2::new_comp(0.0, 0.0), Vector2::new_comp(4.0, 0.0), 1.0);
+
+        assert!(capsule.overlaps_circle(&Circle::new(2.0, 2.0, 1.0)));
+        assert!(!capsule.overlaps_circle(&Circle::new(5.5, 1.5, 1.0)));
+
+        let crossing = Capsule2D::new(Vector2::new_comp(2.0, -3.0), Vector2::new_comp(2.0, 3.0), 0.5);
+        assert!(capsule.overlaps(&crossing));
+
+        let parallel = Capsule2D::new(Vector2::new_comp(1.0, 2.5), Vector2::new_comp(3.0, 2.5), 0.5);
+        assert!(!capsule.overlaps(&parallel));
+        assert!(capsule.overlaps(&Capsule2D::new(parallel.a, parallel.b, 1.5)));
+    }
+
+    #[test]
+    fn capsule3d_contains_body_and_caps() {
+        let capsule = Capsule3D::new(Vector3::new_comp(0.0, 0.0, 0.0), Vector3::new_comp(0.0, 2.0, 0.0), 0.5);
+
+        assert!(capsule.contains(Vector3::new_comp(0.3, 1.0, 0.3)));
+        assert!(!capsule.contains(Vector3::new_comp(0.4, 1.0, 0.4)));
+
+        assert!(capsule.contains(Vector3::new_comp(0.0, 2.5, 0.0)));
+        assert!(capsule.contains(Vector3::new_comp(0.0, -0.4, 0.2)));
+        assert!(!capsule.contains(Vector3::new_comp(0.4, 2.4, 0.0)));
+    }
+
+    #[test]
+    fn capsule3d_overlaps() {
+        let capsule = Capsule3D::new(Vector3::new_comp(0.0, 0.0, 0.0), Vector3::new_comp(0.0, 2.0, 0.0), 0.5);
+
+        assert!(capsule.overlaps_sphere(&Sphere::new(1.0, 1.0, 0.0, 0.5)));
+        assert!(!capsule.overlaps_sphere(&Sphere::new(1.0, 3.0, 0.0, 0.5)));
+
+        let skew = Capsule3D::new(Vector3::new_comp(-1.0, 1.0, 0.8), Vector3::new_comp(1.0, 1.0, 0.8), 0.3);
+        assert!(capsule.overlaps(&skew));
+
+        let far = Capsule3D::new(Vector3::new_comp(-1.0, 1.0, 1.0), Vector3::new_comp(1.0, 1.0, 1.0), 0.3);
+        assert!(!capsule.overlaps(&far));
+    }
+}
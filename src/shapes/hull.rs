@@ -0,0 +1,85 @@
+use num_traits::real::Real;
+
+use crate::vectors::Vector2;
+
+use super::{orientation, Orientation};
+
+/// Convex hull of `points` by Andrew's monotone chain, in counter-clockwise order starting from
+/// the lowest `x` (then lowest `y`) point. Coincident and collinear points are dropped, so
+/// collinear input collapses to its two extremes. Fewer than three distinct points are returned as is.
+///
+/// Panics if any coordinate is NaN.
+pub fn convex_hull<T>(points: &[Vector2<T>]) -> Vec<Vector2<T>>
+where T: Real {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.x.partial_cmp(&b.x).unwrap().then(a.y.partial_cmp(&b.y).unwrap()));
+    sorted.dedup();
+
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    let mut lower = chain(sorted.iter().copied());
+    let mut upper = chain(sorted.iter().rev().copied());
+    lower.pop();
+    upper.pop();
+    lower.append(&mut upper);
+    lower
+}
+
+fn chain<T>(points: impl Iterator<Item = Vector2<T>>) -> Vec<Vector2<T>>
+where T: Real {
+    let mut chain: Vec<Vector2<T>> = Vec::new();
+
+    for point in points {
+        while chain.len() >= 2
+            && orientation(chain[chain.len() - 2], chain[chain.len() - 1], point) != Orientation::CounterClockwise {
+            chain.pop();
+        }
+
+        chain.push(point);
+    }
+
+    chain
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convex_hull_square_with_interior_point() {
+        let points = [
+            Vector2::new_comp(1.0, 1.0),
+            Vector2::new_comp(0.0, 2.0),
+            Vector2::new_comp(2.0, 0.0),
+            Vector2::new_comp(0.5, 1.5),
+            Vector2::new_comp(0.0, 0.0),
+            Vector2::new_comp(2.0, 2.0),
+            Vector2::new_comp(2.0, 2.0),
+            Vector2::new_comp(1.0, 0.0),
+        ];
+
+        assert_eq!(convex_hull(&points), vec![
+            Vector2::new_comp(0.0, 0.0),
+            Vector2::new_comp(2.0, 0.0),
+            Vector2::new_comp(2.0, 2.0),
+            Vector2::new_comp(0.0, 2.0),
+        ]);
+    }
+
+    #[test]
+    fn convex_hull_degenerate_input() {
+        let collinear = [
+            Vector2::new_comp(1.0, 1.0),
+            Vector2::new_comp(3.0, 3.0),
+            Vector2::new_comp(0.0, 0.0),
+            Vector2::new_comp(2.0, 2.0),
+        ];
+        assert_eq!(convex_hull(&collinear), vec![Vector2::new_comp(0.0, 0.0), Vector2::new_comp(3.0, 3.0)]);
+
+        let duplicated = [Vector2::new_comp(1.0, 2.0), Vector2::new_comp(1.0, 2.0)];
+        assert_eq!(convex_hull(&duplicated), vec![Vector2::new_comp(1.0, 2.0)]);
+        assert!(convex_hull::<f64>(&[]).is_empty());
+    }
+}
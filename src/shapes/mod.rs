@@ -2,12 +2,31 @@ use std::ops::{Add, Sub, Mul, AddAssign, SubAssign, DivAssign};
 
 use num_traits::real::Real;
 
+use crate::matrices::{Matrix2, Matrix3};
 use crate::vectors::{Vector2, Vector3, Vector4};
 
 use super::traits::Pi;
 
+mod aabb;
+mod capsule;
+mod hull;
+mod orientation;
+mod plane;
+mod polygon;
+mod ray;
+mod triangle;
+
+pub use aabb::{Aabb, AabbN};
+pub use capsule::{Capsule2D, Capsule3D};
+pub use hull::convex_hull;
+pub use orientation::{orientation, Orientation};
+pub use plane::Plane;
+pub use polygon::Polygon2D;
+pub use ray::{Ray2D, Ray3D};
+pub use triangle::{triangle_area, triangle_area3, Triangle3D};
+
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
-struct Rect<T> {
+pub struct Rect<T> {
     pub x: T,
     pub y: T,
     pub width: T,
@@ -25,6 +44,13 @@ impl<T> Rect<T> {
         Self::new(position.x, position.y, size.x, size.y)
     }
 
+    /// Builds the rect of `size` centered on `center`.
+    #[inline]
+    pub fn from_center_size(center: Vector2<T>, size: Vector2<T>) -> Self
+    where T: Real {
+        Self::new_vectors(center - size / (T::one() + T::one()), size)
+    }
+
     #[inline]
     pub fn set(&mut self, x: T, y: T, width: T, height: T) {
         self.x = x;
@@ -133,19 +159,13 @@ impl<T> Rect<T> {
     #[inline]
     pub fn contains(&self, point: Vector2<T>) -> bool
     where T: PartialOrd + Add<Output = T> + Copy {
-        point.x >= self.x &&
-        point.x <= self.x + self.width &&
-        point.y >= self.y &&
-        point.y <= self.y + self.height
+        AabbN::from(*self).contains(point.into())
     }
 
     #[inline]
     pub fn overlaps(&self, other: &Rect<T>) -> bool
     where T: PartialOrd + Add<Output = T> + Copy {
-        self.x < other.x + other.width &&
-        self.x + self.width > other.x &&
-        self.y < other.y + other.height &&
-        self.y + self.height > other.y
+        AabbN::from(*self).overlaps(&AabbN::from(*other))
     }
 
     #[inline]
@@ -172,6 +192,171 @@ impl<T> Rect<T> {
         let dy = yn - circle.center.y;
         (dx * dx + dy * dy) <= circle.radius * circle.radius
     }
+
+    #[inline]
+    pub fn union(&self, other: &Rect<T>) -> Rect<T>
+    where T: PartialOrd + Add<Output = T> + Sub<Output = T> + Copy {
+        Rect::from(AabbN::from(*self).union(&AabbN::from(*other)))
+    }
+
+    #[inline]
+    pub fn from_points(points: &[Vector2<T>]) -> Option<Rect<T>>
+    where T: PartialOrd + Sub<Output = T> + Copy {
+        let (first, rest) = points.split_first()?;
+        let (min, max) = rest.iter().fold((*first, *first), |(min, max), point| {
+            (Vector2::min(min, *point), Vector2::max(max, *point))
+        });
+
+        Some(Rect::new_vectors(min, max - min))
+    }
+
+    #[inline]
+    pub fn normalized(&self) -> Rect<T>
+    where T: PartialOrd + Add<Output = T> + Sub<Output = T> + Copy {
+        Rect::from(AabbN::from(*self))
+    }
+
+    #[inline]
+    pub fn approx_eq(&self, other: &Rect<T>, epsilon: T) -> bool
+    where T: Real {
+        self.get_position().approx_eq(other.get_position(), epsilon) && self.get_size().approx_eq(other.get_size(), epsilon)
+    }
+
+    #[inline]
+    pub fn closest_point(&self, point: Vector2<T>) -> Vector2<T>
+    where T: Real {
+        let aabb = Aabb::from(*self);
+        point.clamp(aabb.min, aabb.max)
+    }
+
+    /// Signed distance to the edges, negative inside.
+    #[inline]
+    pub fn signed_distance(&self, point: Vector2<T>) -> T
+    where T: Real {
+        let aabb = Aabb::from(*self);
+        let q = (point - aabb.center()).abs() - aabb.size() / (T::one() + T::one());
+        let outside = Vector2::max(q, Vector2::new_comp(T::zero(), T::zero())).magnitude();
+        let inside = q.x.max(q.y).min(T::zero());
+        outside + inside
+    }
+
+    /// Grows every side by `margin`. Negative margins shrink the box and can leave it empty,
+    /// with a negative size, once they exceed half its size.
+    #[inline]
+    pub fn expanded(&self, margin: T) -> Self
+    where T: Add<Output = T> + Sub<Output = T> + Copy {
+        self.expanded_vector(Vector2::new_comp(margin, margin))
+    }
+
+    #[inline]
+    pub fn expanded_vector(&self, margin: Vector2<T>) -> Self
+    where T: Add<Output = T> + Sub<Output = T> + Copy {
+        Self::new_vectors(self.get_position() - margin, self.get_size() + margin + margin)
+    }
+
+    /// Counter-clockwise from the minimum corner: `(x_min, y_min)`, `(x_max, y_min)`,
+    /// `(x_max, y_max)`, `(x_min, y_max)`.
+    #[inline]
+    pub fn corners(&self) -> [Vector2<T>; 4]
+    where T: Add<Output = T> + Copy {
+        let (x_min, x_max) = (self.get_x_min(), self.get_x_max());
+        let (y_min, y_max) = (self.get_y_min(), self.get_y_max());
+
+        [
+            Vector2::new_comp(x_min, y_min),
+            Vector2::new_comp(x_max, y_min),
+            Vector2::new_comp(x_max, y_max),
+            Vector2::new_comp(x_min, y_max),
+        ]
+    }
+
+    /// Edge `i` runs from corner `i` to corner `i + 1` of [`Rect::corners`], wrapping around.
+    #[inline]
+    pub fn edges(&self) -> [Line2D<T>; 4]
+    where T: Add<Output = T> + Copy {
+        let corners = self.corners();
+        [0, 1, 2, 3].map(|i| Line2D::new_vectors(corners[i], corners[(i + 1) % 4]))
+    }
+
+    /// Flattens into `[x, y, width, height]`.
+    #[inline]
+    pub fn to_array(&self) -> [T; 4]
+    where T: Copy {
+        [self.x, self.y, self.width, self.height]
+    }
+
+    /// The inverse of [`Rect::to_array`].
+    #[inline]
+    pub fn from_array(array: [T; 4]) -> Self
+    where T: Copy {
+        Self::new(array[0], array[1], array[2], array[3])
+    }
+
+    /// Scales the rect's corners away from (or, for factors below one, toward) `pivot`.
+    #[inline]
+    pub fn scaled_about(&self, factor: T, pivot: Vector2<T>) -> Self
+    where T: Real {
+        self.scaled_about_vector(Vector2::new_comp(factor, factor), pivot)
+    }
+
+    #[inline]
+    pub fn scaled_about_vector(&self, factor: Vector2<T>, pivot: Vector2<T>) -> Self
+    where T: Real {
+        Self::new_vectors(pivot + (self.get_position() - pivot) * factor, self.get_size() * factor)
+    }
+
+    /// Distance from `point` to the rect, zero when it lies inside. See
+    /// [`Rect::signed_distance`] for the penetration depth.
+    #[inline]
+    pub fn distance_to(&self, point: Vector2<T>) -> T
+    where T: Real {
+        self.signed_distance(point).max(T::zero())
+    }
+
+    /// Splits into four equal quadrants, ordered like [`Rect::corners`]: each child holds the
+    /// corner of the same index.
+    #[inline]
+    pub fn subdivide(&self) -> [Rect<T>; 4]
+    where T: Real {
+        let half = self.get_size() / (T::one() + T::one());
+        let position = self.get_position();
+
+        [(0, 0), (1, 0), (1, 1), (0, 1)].map(|(x, y)| {
+            let offset = Vector2::new_comp(half.x * T::from(x).unwrap(), half.y * T::from(y).unwrap());
+            Rect::new_vectors(position + offset, half)
+        })
+    }
+}
+
+/// Reads the array as `[x, y, width, height]`.
+impl<T> From<[T; 4]> for Rect<T>
+where T: Copy {
+    #[inline]
+    fn from(array: [T; 4]) -> Self {
+        Self::from_array(array)
+    }
+}
+
+/// Flattens into `[x, y, width, height]`.
+impl<T> From<Rect<T>> for [T; 4]
+where T: Copy {
+    #[inline]
+    fn from(shape: Rect<T>) -> Self {
+        shape.to_array()
+    }
+}
+
+impl Rect<i32> {
+    /// Every integer cell covered by the rect, row by row from the minimum corner.
+    /// `x` ranges over `x..x + width` and `y` over `y..y + height`, so empty or
+    /// negative sizes yield nothing. Cells past `i32::MAX` are left out instead of overflowing.
+    #[inline]
+    pub fn cells(&self) -> impl Iterator<Item = Vector2<i32>> {
+        let end = |start: i32, size: i32| (start as i64 + size as i64).min(i32::MAX as i64 + 1);
+        let (x_min, x_max) = (self.x as i64, end(self.x, self.width));
+        (self.y as i64..end(self.y, self.height))
+            .flat_map(move |y| (x_min..x_max).map(move |x| Vector2::new_comp(x as i32, y as i32)))
+    }
 }
 
 impl<T> From<Area2D<T>> for Rect<T>
@@ -234,7 +419,7 @@ where T: Add<Output = T> + Sub<Output = T> + Copy {
 
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
-struct Area2D<T> {
+pub struct Area2D<T> {
     pub lower_left: Vector2<T>,
     pub upper_right: Vector2<T>,
 }
@@ -250,6 +435,19 @@ impl<T> Area2D<T> {
         Area2D { lower_left, upper_right }
     }
 
+    /// Same as [`Area2D::new_vectors`], named to match [`Bounds2D::from_min_max`].
+    #[inline]
+    pub fn from_min_max(min: Vector2<T>, max: Vector2<T>) -> Self {
+        Self::new_vectors(min, max)
+    }
+
+    #[inline]
+    pub fn from_center_size(center: Vector2<T>, size: Vector2<T>) -> Self
+    where T: Real {
+        let half_size = size / (T::one() + T::one());
+        Self::new_vectors(center - half_size, center + half_size)
+    }
+
     #[inline]
     pub fn set(&mut self, lower_left_x: T, lower_left_y: T, upper_right_x: T, upper_right_y: T) {
         self.lower_left.x = lower_left_x;
@@ -379,19 +577,13 @@ impl<T> Area2D<T> {
     #[inline]
     pub fn contains(&self, point: Vector2<T>) -> bool
     where T: PartialOrd + Copy {
-        point.x >= self.lower_left.x &&
-        point.x <= self.upper_right.x &&
-        point.y >= self.lower_left.y &&
-        point.y <= self.upper_right.y
+        AabbN::from(*self).contains(point.into())
     }
 
     #[inline]
     pub fn overlaps(&self, other: &Area2D<T>) -> bool
     where T: PartialOrd + Copy {
-        self.lower_left.x < other.upper_right.x &&
-        self.upper_right.x > other.lower_left.x &&
-        self.lower_left.y < other.upper_right.y &&
-        self.upper_right.y > other.lower_left.y
+        AabbN::from(*self).overlaps(&AabbN::from(*other))
     }
 
     #[inline]
@@ -418,6 +610,52 @@ impl<T> Area2D<T> {
         let rect = Rect::from(*self);
         rect.overlaps_circle(*circle)
     }
+
+    #[inline]
+    pub fn encapsulate(&mut self, point: Vector2<T>)
+    where T: PartialOrd + Copy {
+        self.lower_left = Vector2::min(self.lower_left, point);
+        self.upper_right = Vector2::max(self.upper_right, point);
+    }
+
+    #[inline]
+    pub fn encapsulate_area(&mut self, other: &Area2D<T>)
+    where T: PartialOrd + Copy {
+        self.lower_left = Vector2::min(self.lower_left, other.lower_left);
+        self.upper_right = Vector2::max(self.upper_right, other.upper_right);
+    }
+
+    /// Grows every side by `margin`. Negative margins shrink the box and can leave it empty,
+    /// with a negative size, once they exceed half its size.
+    #[inline]
+    pub fn expanded(&self, margin: T) -> Self
+    where T: Add<Output = T> + Sub<Output = T> + Copy {
+        self.expanded_vector(Vector2::new_comp(margin, margin))
+    }
+
+    #[inline]
+    pub fn expanded_vector(&self, margin: Vector2<T>) -> Self
+    where T: Add<Output = T> + Sub<Output = T> + Copy {
+        Self::new_vectors(self.lower_left - margin, self.upper_right + margin)
+    }
+}
+
+/// Reads the array as `[lower_left_x, lower_left_y, upper_right_x, upper_right_y]`.
+impl<T> From<[T; 4]> for Area2D<T>
+where T: Copy {
+    #[inline]
+    fn from(array: [T; 4]) -> Self {
+        Self::new(array[0], array[1], array[2], array[3])
+    }
+}
+
+/// Flattens into `[lower_left_x, lower_left_y, upper_right_x, upper_right_y]`.
+impl<T> From<Area2D<T>> for [T; 4]
+where T: Copy {
+    #[inline]
+    fn from(shape: Area2D<T>) -> Self {
+        [shape.lower_left.x, shape.lower_left.y, shape.upper_right.x, shape.upper_right.y]
+    }
 }
 
 impl<T> From<Rect<T>> for Area2D<T>
@@ -469,7 +707,7 @@ where T: Add<Output = T> + Sub<Output = T> + Copy {
 
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
-struct Bounds2D<T> {
+pub struct Bounds2D<T> {
     pub center: Vector2<T>,
     pub extents: Vector2<T>,
 }
@@ -486,6 +724,19 @@ impl<T> Bounds2D<T> {
         Bounds2D { center, extents }
     }
 
+    #[inline]
+    pub fn from_min_max(min: Vector2<T>, max: Vector2<T>) -> Self
+    where T: Real {
+        let two = T::one() + T::one();
+        Self::new_vectors((min + max) / two, (max - min) / two)
+    }
+
+    #[inline]
+    pub fn from_center_size(center: Vector2<T>, size: Vector2<T>) -> Self
+    where T: Real {
+        Self::new_vectors(center, size / (T::one() + T::one()))
+    }
+
     #[inline]
     pub fn set(&mut self, center_x: T, center_y: T, extents_x: T, extents_y: T)
     where T: Copy {
@@ -594,13 +845,20 @@ impl<T> Bounds2D<T> {
         self.extents -= half_delta;
     }
 
+    #[inline]
+    pub fn set_size_vector(&mut self, size: Vector2<T>)
+    where T: SubAssign + Copy + Real {
+        self.set_size(size.x, size.y);
+    }
+
+    /// Inclusive: points on the boundary count as contained, like every other shape.
     #[inline]
     pub fn contains(&self, point: Vector2<T>) -> bool
     where T: Add<Output = T> + Sub<Output = T> + PartialOrd + Copy {
-        self.center.x - self.extents.x < point.x &&
-        self.center.x + self.extents.x > point.x &&
-        self.center.y - self.extents.y < point.y &&
-        self.center.y + self.extents.y > point.y
+        self.center.x - self.extents.x <= point.x &&
+        self.center.x + self.extents.x >= point.x &&
+        self.center.y - self.extents.y <= point.y &&
+        self.center.y + self.extents.y >= point.y
     }
 
     #[inline]
@@ -630,10 +888,110 @@ impl<T> Bounds2D<T> {
         self.center.y + self.extents.y > area.get_y_min()
     }
 
-    pub fn overlaps_circle(&self, circle: Circle<T>)
+    #[inline]
+    pub fn overlaps_circle(&self, circle: &Circle<T>) -> bool
     where T: Real {
         let rect = Rect::from(*self);
-        rect.overlaps_circle(circle);
+        rect.overlaps_circle(*circle)
+    }
+
+    #[inline]
+    pub fn encapsulate(&mut self, point: Vector2<T>)
+    where T: Real {
+        self.encapsulate_min_max(point, point);
+    }
+
+    #[inline]
+    pub fn encapsulate_bounds(&mut self, other: &Bounds2D<T>)
+    where T: Real {
+        self.encapsulate_min_max(other.center - other.extents, other.center + other.extents);
+    }
+
+    #[inline]
+    fn encapsulate_min_max(&mut self, min: Vector2<T>, max: Vector2<T>)
+    where T: Real {
+        let min = Vector2::min(self.center - self.extents, min);
+        let max = Vector2::max(self.center + self.extents, max);
+        let two = T::one() + T::one();
+        self.center = (min + max) / two;
+        self.extents = (max - min) / two;
+    }
+
+    /// Grows every side by `margin`. Negative margins shrink the box and can leave it empty,
+    /// with a negative size, once they exceed the extents.
+    #[inline]
+    pub fn expanded(&self, margin: T) -> Self
+    where T: Add<Output = T> + Copy {
+        self.expanded_vector(Vector2::new_comp(margin, margin))
+    }
+
+    #[inline]
+    pub fn expanded_vector(&self, margin: Vector2<T>) -> Self
+    where T: Add<Output = T> + Copy {
+        Self::new_vectors(self.center, self.extents + margin)
+    }
+
+    /// Flattens into `[center_x, center_y, extents_x, extents_y]`.
+    #[inline]
+    pub fn to_array(&self) -> [T; 4]
+    where T: Copy {
+        [self.center.x, self.center.y, self.extents.x, self.extents.y]
+    }
+
+    /// The inverse of [`Bounds2D::to_array`].
+    #[inline]
+    pub fn from_array(array: [T; 4]) -> Self
+    where T: Copy {
+        Self::new(array[0], array[1], array[2], array[3])
+    }
+
+    /// Scales the extents about the center.
+    #[inline]
+    pub fn scaled(&self, factor: T) -> Self
+    where T: Mul<Output = T> + Copy {
+        self.scaled_vector(Vector2::new_comp(factor, factor))
+    }
+
+    #[inline]
+    pub fn scaled_vector(&self, factor: Vector2<T>) -> Self
+    where T: Mul<Output = T> + Copy {
+        Self::new_vectors(self.center, self.extents * factor)
+    }
+
+    /// The axis-aligned bounds enclosing all four corners after transforming them by `matrix`.
+    #[inline]
+    pub fn transformed(&self, matrix: &Matrix2<T>) -> Self
+    where T: Real {
+        let corners = Rect::from(*self).corners().map(|corner| *matrix * corner);
+        let min = corners.iter().fold(corners[0], |min, corner| Vector2::min(min, *corner));
+        let max = corners.iter().fold(corners[0], |max, corner| Vector2::max(max, *corner));
+        let two = T::one() + T::one();
+        Self::new_vectors((min + max) / two, (max - min) / two)
+    }
+
+    /// Shorthand for [`Bounds2D::transformed`] by a rotation of `radians` about the origin.
+    #[inline]
+    pub fn rotated(&self, radians: T) -> Self
+    where T: Real {
+        self.transformed(&Matrix2::from_rotation(radians))
+    }
+}
+
+/// Reads the array as `[center_x, center_y, extents_x, extents_y]`.
+impl<T> From<[T; 4]> for Bounds2D<T>
+where T: Copy {
+    #[inline]
+    fn from(array: [T; 4]) -> Self {
+        Self::from_array(array)
+    }
+}
+
+/// Flattens into `[center_x, center_y, extents_x, extents_y]`.
+impl<T> From<Bounds2D<T>> for [T; 4]
+where T: Copy {
+    #[inline]
+    fn from(shape: Bounds2D<T>) -> Self {
+        shape.to_array()
     }
 }
 
@@ -657,7 +1015,7 @@ where T: Real {
             area.get_center().x,
             area.get_center().y,
             area.get_width() / (T::one() + T::one()),
-            area.get_width() / (T::one() + T::one()))
+            area.get_height() / (T::one() + T::one()))
     }
 }
 
@@ -681,7 +1039,7 @@ where T: Real {
             area.get_center().x,
             area.get_center().y,
             area.get_width() / (T::one() + T::one()),
-            area.get_width() / (T::one() + T::one()))
+            area.get_height() / (T::one() + T::one()))
     }
 }
 
@@ -705,14 +1063,14 @@ where T: Real {
             area.get_center().x,
             area.get_center().y,
             area.get_width() / (T::one() + T::one()),
-            area.get_width() / (T::one() + T::one()))
+            area.get_height() / (T::one() + T::one()))
     }
 }
 
 
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
-struct Circle<T> {
+pub struct Circle<T> {
     pub center: Vector2<T>,
     pub radius: T,
 }
@@ -743,13 +1101,13 @@ impl<T> Circle<T> {
     #[inline]
     pub fn get_circumference(&self) -> T
     where T: Real + Pi<Output = T> {
-        self.radius * (T::one() + T::one()) * T::pi()
+        self.radius * T::tau()
     }
 
     #[inline]
     pub fn set_circumference(&mut self, circumference: T)
     where T: Real + Pi<Output = T> {
-        self.radius = circumference / ((T::one() + T::one()) * T::pi());
+        self.radius = circumference / T::tau();
     }
 
     #[inline]
@@ -780,92 +1138,245 @@ impl<T> Circle<T> {
         let radius_sum = self.radius + other.radius;
         distance_squared < radius_sum * radius_sum
     }
-}
 
-impl<T> From<Sphere<T>> for Circle<T> {
     #[inline]
-    fn from(sphere: Sphere<T>) -> Self {
-        Self::new_vector(Vector2::from(sphere.center), sphere.radius)
+    pub fn scaled(&self, factor: T) -> Self
+    where T: Mul<Output = T> + Copy {
+        Self::new_vector(self.center, self.radius * factor)
     }
-}
 
-impl<T> From<HyperSphere<T>> for Circle<T> {
     #[inline]
-    fn from(hyper_sphere: HyperSphere<T>) -> Self {
-        Self::new_vector(Vector2::from(hyper_sphere.center), hyper_sphere.radius)
+    pub fn approx_eq(&self, other: &Circle<T>, epsilon: T) -> bool
+    where T: Real {
+        self.center.approx_eq(other.center, epsilon) && (self.radius - other.radius).abs() <= epsilon
     }
-}
 
+    #[inline]
+    pub fn intersects(&self, other: &Circle<T>) -> CircleIntersection<T>
+    where T: Real {
+        let delta = other.center - self.center;
+        let distance = delta.magnitude();
 
+        if distance == T::zero() && self.radius == other.radius {
+            return CircleIntersection::Coincident;
+        }
 
-#[derive(Debug, Clone, Copy, PartialEq, Default)]
-struct Line2D<T> {
-    pub start: Vector2<T>,
-    pub end: Vector2<T>,
-}
+        if distance > self.radius + other.radius || distance < (self.radius - other.radius).abs() {
+            return CircleIntersection::None;
+        }
 
-impl<T> Line2D<T> {
+        let a = (self.radius * self.radius - other.radius * other.radius + distance * distance) / (distance + distance);
+        let h_squared = self.radius * self.radius - a * a;
+        let midpoint = self.center + delta * (a / distance);
+
+        if h_squared <= T::zero() {
+            return CircleIntersection::Tangent(midpoint);
+        }
+
+        let offset = Vector2::perpendicular(delta) * (h_squared.sqrt() / distance);
+        CircleIntersection::Two(midpoint + offset, midpoint - offset)
+    }
+
+    /// Nearest point on the surface. A `point` at the center has no direction, so the
+    /// point on the surface along [`Vector2::right`] is returned.
     #[inline]
-    pub fn new(start_x: T, start_y: T, end_x: T, end_y: T) -> Line2D<T> {
-        Self::new_vectors(Vector2::new_comp(start_x, start_y), Vector2::new_comp(end_x, end_y))
+    pub fn closest_point(&self, point: Vector2<T>) -> Vector2<T>
+    where T: Real {
+        let delta = point - self.center;
+        let distance = delta.magnitude();
+
+        if distance == T::zero() {
+            return self.center + Vector2::right() * self.radius;
+        }
+
+        self.center + delta * (self.radius / distance)
     }
 
+    /// Signed distance to the surface, negative inside.
     #[inline]
-    pub fn new_vectors(start: Vector2<T>, end: Vector2<T>) -> Line2D<T> {
-        Line2D { start, end, }
+    pub fn distance_to_point(&self, point: Vector2<T>) -> T
+    where T: Real {
+        Vector2::distance(point, self.center) - self.radius
     }
 
+    /// A circle centered on the average of `points` that encloses all of them.
+    /// It is cheap to compute but not the minimal enclosing circle. The radius is padded
+    /// by a relative epsilon so rounding never leaves one of `points` outside.
     #[inline]
-    pub fn set(&mut self, start_x: T, start_y: T, end_x: T, end_y: T) {
-        self.start.x = start_x;
-        self.start.y = start_y;
-        self.end.x = end_x;
-        self.end.y = end_y;
+    pub fn bounding(points: &[Vector2<T>]) -> Option<Self>
+    where T: Real {
+        let center = Vector2::average(points.iter().copied())?;
+        let radius = points.iter()
+            .fold(T::zero(), |radius, point| radius.max(Vector2::distance(center, *point)));
+
+        Some(Self::new_vector(center, radius * (T::one() + T::epsilon())))
     }
 
+    /// Whether `other` lies entirely inside `self`, touching the boundary included.
     #[inline]
-    pub fn set_vectors(&mut self, start: Vector2<T>, end: Vector2<T>) {
-        self.start = start;
-        self.end = end;
+    pub fn contains_circle(&self, other: &Circle<T>) -> bool
+    where T: Real {
+        Vector2::distance(self.center, other.center) + other.radius <= self.radius
     }
 
+    /// Whether the surfaces touch at a single point within `epsilon`, either from the outside
+    /// or with one circle inside the other. Coincident circles are not tangent.
     #[inline]
-    pub fn get_length(&self) -> T
-    where T: Sub<Output = T> + Mul<Output = T> + Real {
-        let delta = self.end - self.start;
-        delta.magnitude()
+    pub fn is_tangent_to(&self, other: &Circle<T>, epsilon: T) -> bool
+    where T: Real {
+        let distance = Vector2::distance(self.center, other.center);
+        let external = (distance - (self.radius + other.radius)).abs() <= epsilon;
+        let internal = distance > epsilon && (distance - (self.radius - other.radius).abs()).abs() <= epsilon;
+        external || internal
     }
 
+    /// Flattens into `[center_x, center_y, radius]`.
     #[inline]
-    pub fn set_length(&mut self, length: T)
-    where T: DivAssign + Real {
-        let delta = self.end - self.start;
-        let delta_normalized = delta.normalized();
-        self.end = self.start + delta_normalized * length;
+    pub fn to_array(&self) -> [T; 3]
+    where T: Copy {
+        [self.center.x, self.center.y, self.radius]
     }
 
+    /// The inverse of [`Circle::to_array`].
     #[inline]
-    pub fn get_sqr_length(&self) -> T
-    where T: Sub<Output = T> + Mul<Output = T> + Real {
-        let delta = self.end - self.start;
-        delta.sqr_magnitude()
+    pub fn from_array(array: [T; 3]) -> Self
+    where T: Copy {
+        Self::new(array[0], array[1], array[2])
     }
 
+    /// Point on the boundary `angle` radians counter-clockwise from the positive x axis.
     #[inline]
-    pub fn set_sqr_length(&mut self, sqr_length: T)
-    where T: DivAssign + Real {
-        self.set_length(sqr_length.sqrt());
+    pub fn point_on_boundary(&self, angle: T) -> Vector2<T>
+    where T: Real {
+        self.center + Vector2::from_polar(self.radius, angle)
     }
 
+    /// Gap between the two surfaces. Never negative: overlapping circles return zero
+    /// rather than a penetration depth.
     #[inline]
-    pub fn get_direction(&self) -> Vector2<T>
-    where T: DivAssign + Real {
-        let delta = self.end - self.start;
-        delta.normalized()
+    pub fn distance_to(&self, other: &Circle<T>) -> T
+    where T: Real {
+        (Vector2::distance(self.center, other.center) - self.radius - other.radius).max(T::zero())
     }
 
+    /// Gap between the surface and `rect`, zero when they overlap.
     #[inline]
-    pub fn set_direction(&mut self, direction: Vector2<T>)
+    pub fn distance_to_rect(&self, rect: &Rect<T>) -> T
+    where T: Real {
+        (rect.signed_distance(self.center) - self.radius).max(T::zero())
+    }
+}
+
+/// Reads the array as `[center_x, center_y, radius]`.
+impl<T> From<[T; 3]> for Circle<T>
+where T: Copy {
+    #[inline]
+    fn from(array: [T; 3]) -> Self {
+        Self::from_array(array)
+    }
+}
+
+/// Flattens into `[center_x, center_y, radius]`.
+impl<T> From<Circle<T>> for [T; 3]
+where T: Copy {
+    #[inline]
+    fn from(shape: Circle<T>) -> Self {
+        shape.to_array()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CircleIntersection<T> {
+    None,
+    Tangent(Vector2<T>),
+    Two(Vector2<T>, Vector2<T>),
+    Coincident,
+}
+
+impl<T> From<Sphere<T>> for Circle<T> {
+    #[inline]
+    fn from(sphere: Sphere<T>) -> Self {
+        Self::new_vector(Vector2::from(sphere.center), sphere.radius)
+    }
+}
+
+impl<T> From<HyperSphere<T>> for Circle<T> {
+    #[inline]
+    fn from(hyper_sphere: HyperSphere<T>) -> Self {
+        Self::new_vector(Vector2::from(hyper_sphere.center), hyper_sphere.radius)
+    }
+}
+
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Line2D<T> {
+    pub start: Vector2<T>,
+    pub end: Vector2<T>,
+}
+
+impl<T> Line2D<T> {
+    #[inline]
+    pub fn new(start_x: T, start_y: T, end_x: T, end_y: T) -> Line2D<T> {
+        Self::new_vectors(Vector2::new_comp(start_x, start_y), Vector2::new_comp(end_x, end_y))
+    }
+
+    #[inline]
+    pub fn new_vectors(start: Vector2<T>, end: Vector2<T>) -> Line2D<T> {
+        Line2D { start, end, }
+    }
+
+    #[inline]
+    pub fn set(&mut self, start_x: T, start_y: T, end_x: T, end_y: T) {
+        self.start.x = start_x;
+        self.start.y = start_y;
+        self.end.x = end_x;
+        self.end.y = end_y;
+    }
+
+    #[inline]
+    pub fn set_vectors(&mut self, start: Vector2<T>, end: Vector2<T>) {
+        self.start = start;
+        self.end = end;
+    }
+
+    #[inline]
+    pub fn get_length(&self) -> T
+    where T: Sub<Output = T> + Mul<Output = T> + Real {
+        let delta = self.end - self.start;
+        delta.magnitude()
+    }
+
+    #[inline]
+    pub fn set_length(&mut self, length: T)
+    where T: DivAssign + Real {
+        let delta = self.end - self.start;
+        let delta_normalized = delta.normalized();
+        self.end = self.start + delta_normalized * length;
+    }
+
+    #[inline]
+    pub fn get_sqr_length(&self) -> T
+    where T: Sub<Output = T> + Mul<Output = T> + Real {
+        let delta = self.end - self.start;
+        delta.sqr_magnitude()
+    }
+
+    #[inline]
+    pub fn set_sqr_length(&mut self, sqr_length: T)
+    where T: DivAssign + Real {
+        self.set_length(sqr_length.sqrt());
+    }
+
+    #[inline]
+    pub fn get_direction(&self) -> Vector2<T>
+    where T: DivAssign + Real {
+        let delta = self.end - self.start;
+        delta.normalized()
+    }
+
+    #[inline]
+    pub fn set_direction(&mut self, direction: Vector2<T>)
     where T: Real {
         let delta = self.end - self.start;
         let length = delta.magnitude();
@@ -898,102 +1409,227 @@ impl<T> Line2D<T> {
         self.end = center + delta / (T::one() + T::one());
     }
 
-    // Ported from https://forum.unity.com/threads/line-intersection.17384/
+    #[inline]
+    pub fn scaled_about_center(&self, factor: T) -> Self
+    where T: Real {
+        let center = self.get_center();
+        let half_delta = self.get_delta() * factor / (T::one() + T::one());
+        Self::new_vectors(center - half_delta, center + half_delta)
+    }
+
+    /// Where the two segments cross, ends included. Parallel and collinear segments
+    /// never return a point, even when they overlap.
     #[inline]
     pub fn intersects(&self, other: &Line2D<T>) -> Option<Vector2<T>>
-    where T: Real + PartialOrd {
-        let p1 = self.start;
-        let p2 = self.end;
-        let p3 = other.start;
-        let p4 = other.end;
-        
-        let ax = p2.x-p1.x;
-        let bx = p3.x-p4.x;
-        let x1lo;
-        let x1hi;
-        
-        if ax < T::zero() {
-            x1lo = p2.x;
-            x1hi = p1.x;
+    where T: Real {
+        let (t, u) = self.intersection_parameters(other)?;
+        let in_range = |value: T| value >= T::zero() && value <= T::one();
+
+        if in_range(t) && in_range(u) {
+            Some(self.start + self.get_delta() * t)
         } else {
-            x1lo = p1.x;
-            x1hi = p2.x;
+            None
         }
+    }
 
-        if bx > T::zero() {
-            if x1hi < p4.x || p3.x < x1lo {
-                return None;
-            }
-        } else {
-            if x1hi < p3.x || p4.x < x1lo {
-                return None;
-            }
+    /// Where the infinite lines through both segments cross, or `None` when they are parallel.
+    #[inline]
+    pub fn intersect_infinite(&self, other: &Line2D<T>) -> Option<Vector2<T>>
+    where T: Real {
+        let (t, _) = self.intersection_parameters(other)?;
+        Some(self.start + self.get_delta() * t)
+    }
+
+    /// The crossing as fractions `(t, u)` along `self` and `other`, `None` when parallel.
+    #[inline]
+    fn intersection_parameters(&self, other: &Line2D<T>) -> Option<(T, T)>
+    where T: Real {
+        let r = self.get_delta();
+        let s = other.get_delta();
+        let denominator = Vector2::perp_dot(r, s);
+
+        if denominator == T::zero() {
+            return None;
         }
 
-        let ay = p2.y-p1.y;
-        let by = p3.y-p4.y;
-        let y1lo;
-        let y1hi;
+        let offset = other.start - self.start;
+        Some((Vector2::perp_dot(offset, s) / denominator, Vector2::perp_dot(offset, r) / denominator))
+    }
 
-        if ay < T::zero() {
-            y1lo = p2.y;
-            y1hi = p1.y;
-        } else {
-            y1lo = p1.y;
-            y1hi = p2.y;
+    #[inline]
+    pub fn closest_point_on_line(&self, point: Vector2<T>) -> Vector2<T>
+    where T: Real {
+        let delta = self.get_delta();
+        let sqr_length = delta.sqr_magnitude();
+
+        if sqr_length == T::zero() {
+            return self.start;
         }
 
-        if by > T::zero() {
-            if y1hi < p4.y || p3.y < y1lo {
-                return None;
-            }
-        } else {
-            if y1hi < p3.y || p4.y < y1lo {
-                return None;
-            }
+        self.start + delta * (Vector2::dot(point - self.start, delta) / sqr_length)
+    }
+
+    #[inline]
+    pub fn closest_point(&self, point: Vector2<T>) -> Vector2<T>
+    where T: Real {
+        let delta = self.get_delta();
+        let sqr_length = delta.sqr_magnitude();
+
+        if sqr_length == T::zero() {
+            return self.start;
         }
 
-        let cx = p1.x-p3.x;
-        let cy = p1.y-p3.y;
-        let d = by*cx - bx*cy; // alpha numerator
-        let f = ay*bx - ax*by; // both denominator
+        let t = Vector2::dot(point - self.start, delta) / sqr_length;
+        self.start + delta * t.max(T::zero()).min(T::one())
+    }
 
-        // alpha tests
-        if f > T::zero() {
-            if d < T::zero() || d > f {
-                return None;
-            }
-        } else {
-            if d > T::zero() || d < f {
-                return None;
-            }
+    #[inline]
+    pub fn distance_to_point(&self, point: Vector2<T>) -> T
+    where T: Real {
+        Vector2::distance(self.closest_point(point), point)
+    }
+
+    #[inline]
+    pub fn intersect_circle(&self, circle: &Circle<T>) -> Option<(Vector2<T>, Option<Vector2<T>>)>
+    where T: Real {
+        let delta = self.get_delta();
+        let offset = self.start - circle.center;
+
+        let a = Vector2::dot(delta, delta);
+        let b = Vector2::dot(offset, delta);
+        let c = Vector2::dot(offset, offset) - circle.radius * circle.radius;
+        let discriminant = b * b - a * c;
+
+        if a == T::zero() || discriminant < T::zero() {
+            return None;
         }
 
-        let e = ax*cy - ay*cx; // beta numerator
+        let root = discriminant.sqrt();
+        let in_range = |t: T| t >= T::zero() && t <= T::one();
+        let near = (-b - root) / a;
+        let far = (-b + root) / a;
 
-        // beta tests
-        if f > T::zero() {
-            if e < T::zero() || e > f {
-                return None;
+        let near = in_range(near).then(|| self.start + delta * near);
+        let far = (root > T::zero() && in_range(far)).then(|| self.start + delta * far);
+
+        match (near, far) {
+            (Some(near), far) => Some((near, far)),
+            (None, Some(far)) => Some((far, None)),
+            (None, None) => None,
+        }
+    }
+
+    /// `t` in `[0, 1]` stays on the segment, values outside extrapolate along the line.
+    #[inline]
+    pub fn point_at(&self, t: T) -> Vector2<T>
+    where T: Real {
+        self.start + self.get_delta() * t
+    }
+
+    /// `n` evenly spaced points from `start` to `end`, both included.
+    #[inline]
+    pub fn sample(&self, n: usize) -> Vec<Vector2<T>>
+    where T: Real {
+        if n == 1 {
+            return vec![self.start];
+        }
+
+        let last = T::from(n.saturating_sub(1)).unwrap();
+        (0..n).map(|i| self.point_at(T::from(i).unwrap() / last)).collect()
+    }
+
+    /// The part of the segment inside `rect` (boundary included), by Liang-Barsky clipping.
+    #[inline]
+    pub fn clip_to_rect(&self, rect: &Rect<T>) -> Option<Line2D<T>>
+    where T: Real {
+        let aabb = AabbN::from(*rect);
+        let delta = self.get_delta();
+        let p = [-delta.x, delta.x, -delta.y, delta.y];
+        let q = [
+            self.start.x - aabb.min[0],
+            aabb.max[0] - self.start.x,
+            self.start.y - aabb.min[1],
+            aabb.max[1] - self.start.y,
+        ];
+
+        let mut t_enter = T::zero();
+        let mut t_exit = T::one();
+
+        for (p, q) in p.into_iter().zip(q) {
+            if p == T::zero() {
+                if q < T::zero() {
+                    return None;
+                }
+
+                continue;
             }
-        } else {
-            if e > T::zero() || e < f {
+
+            let t = q / p;
+
+            if p < T::zero() {
+                t_enter = t_enter.max(t);
+            } else {
+                t_exit = t_exit.min(t);
+            }
+
+            if t_enter > t_exit {
                 return None;
             }
         }
 
-        // check if they are parallel
-        if f == T::zero() {
-            return None;
-        }
-        
-        // compute intersection coordinates
-        let mut num = d*ax; // numerator
-        let x = p1.x + num / f;
-        num = d*ay;
-        let y = p1.y + num / f;
+        Some(Line2D::new_vectors(self.point_at(t_enter), self.point_at(t_exit)))
+    }
+
+    #[inline]
+    pub fn intersects_rect(&self, rect: &Rect<T>) -> bool
+    where T: Real {
+        self.clip_to_rect(rect).is_some()
+    }
+
+    #[inline]
+    pub fn distance_to_segment(&self, other: &Line2D<T>) -> T
+    where T: Real {
+        Line3D::from(*self).distance_to_segment(&Line3D::from(*other))
+    }
+
+    /// Unit normal on the left of the segment, the direction rotated a quarter turn
+    /// counter-clockwise. Negate it for the right-hand normal.
+    #[inline]
+    pub fn normal(&self) -> Vector2<T>
+    where T: DivAssign + Real {
+        Vector2::perpendicular(self.get_direction())
+    }
+
+    /// Flattens into `[start_x, start_y, end_x, end_y]`.
+    #[inline]
+    pub fn to_array(&self) -> [T; 4]
+    where T: Copy {
+        [self.start.x, self.start.y, self.end.x, self.end.y]
+    }
+
+    /// The inverse of [`Line2D::to_array`].
+    #[inline]
+    pub fn from_array(array: [T; 4]) -> Self
+    where T: Copy {
+        Self::new(array[0], array[1], array[2], array[3])
+    }
+}
+
+/// Reads the array as `[start_x, start_y, end_x, end_y]`.
+impl<T> From<[T; 4]> for Line2D<T>
+where T: Copy {
+    #[inline]
+    fn from(array: [T; 4]) -> Self {
+        Self::from_array(array)
+    }
+}
 
-        Some(Vector2::new_comp(x, y))
+/// Flattens into `[start_x, start_y, end_x, end_y]`.
+impl<T> From<Line2D<T>> for [T; 4]
+where T: Copy {
+    #[inline]
+    fn from(shape: Line2D<T>) -> Self {
+        shape.to_array()
     }
 }
 
@@ -1013,8 +1649,8 @@ impl<T> From<Line4D<T>> for Line2D<T> {
 
 
 
-
-struct Cube<T> {
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Cube<T> {
     pub x: T,
     pub y: T,
     pub z: T,
@@ -1034,6 +1670,13 @@ impl<T> Cube<T> {
         Self::new(position.x, position.y, position.z, size.x, size.y, size.z)
     }
 
+    /// Builds the cube of `size` centered on `center`.
+    #[inline]
+    pub fn from_center_size(center: Vector3<T>, size: Vector3<T>) -> Self
+    where T: Real {
+        Self::new_vectors(center - size / (T::one() + T::one()), size)
+    }
+
     #[inline]
     pub fn set(&mut self, x: T, y: T, z: T, width: T, height: T, depth: T) {
         self.x = x;
@@ -1173,23 +1816,13 @@ impl<T> Cube<T> {
     #[inline]
     pub fn contains(&self, point: Vector3<T>) -> bool
     where T: PartialOrd + Add<Output = T> + Copy {
-        point.x >= self.x &&
-        point.x <= self.x + self.width &&
-        point.y >= self.y &&
-        point.y <= self.y + self.height &&
-        point.z >= self.z &&
-        point.z <= self.z + self.depth
+        AabbN::from(*self).contains(point.into())
     }
 
     #[inline]
     pub fn overlaps(&self, other: &Cube<T>) -> bool
     where T: PartialOrd + Add<Output = T> + Copy {
-        self.x < other.x + other.width &&
-        self.x + self.width > other.x &&
-        self.y < other.y + other.height &&
-        self.y + self.height > other.y &&
-        self.z < other.z + other.depth &&
-        self.z + self.depth > other.z
+        AabbN::from(*self).overlaps(&AabbN::from(*other))
     }
 
     #[inline]
@@ -1206,10 +1839,119 @@ impl<T> Cube<T> {
         self.overlaps(&other)
     }
 
-    // #[inline]
-    // pub fn overlaps_sphere(&self, circle: Circle<T>) -> bool {
-    //    
-    // }
+    #[inline]
+    pub fn overlaps_sphere(&self, sphere: Sphere<T>) -> bool
+    where T: Real {
+        let xn: T = self.x.max(sphere.center.x.min(self.get_x_max()));
+        let yn: T = self.y.max(sphere.center.y.min(self.get_y_max()));
+        let zn: T = self.z.max(sphere.center.z.min(self.get_z_max()));
+
+        let dx = xn - sphere.center.x;
+        let dy = yn - sphere.center.y;
+        let dz = zn - sphere.center.z;
+        (dx * dx + dy * dy + dz * dz) <= sphere.radius * sphere.radius
+    }
+
+    #[inline]
+    pub fn approx_eq(&self, other: &Cube<T>, epsilon: T) -> bool
+    where T: Real {
+        self.get_position().approx_eq(other.get_position(), epsilon) && self.get_size().approx_eq(other.get_size(), epsilon)
+    }
+
+    #[inline]
+    pub fn closest_point(&self, point: Vector3<T>) -> Vector3<T>
+    where T: Real {
+        let aabb = Aabb::from(*self);
+        point.clamp(aabb.min, aabb.max)
+    }
+
+    /// Signed distance to the faces, negative inside.
+    #[inline]
+    pub fn signed_distance(&self, point: Vector3<T>) -> T
+    where T: Real {
+        let aabb = Aabb::from(*self);
+        let q = (point - aabb.center()).abs() - aabb.size() / (T::one() + T::one());
+        let outside = Vector3::max(q, Vector3::new_comp(T::zero(), T::zero(), T::zero())).magnitude();
+        let inside = q.x.max(q.y).max(q.z).min(T::zero());
+        outside + inside
+    }
+
+    /// Grows every side by `margin`. Negative margins shrink the box and can leave it empty,
+    /// with a negative size, once they exceed half its size.
+    #[inline]
+    pub fn expanded(&self, margin: T) -> Self
+    where T: Add<Output = T> + Sub<Output = T> + Copy {
+        self.expanded_vector(Vector3::new_comp(margin, margin, margin))
+    }
+
+    #[inline]
+    pub fn expanded_vector(&self, margin: Vector3<T>) -> Self
+    where T: Add<Output = T> + Sub<Output = T> + Copy {
+        Self::new_vectors(self.get_position() - margin, self.get_size() + margin + margin)
+    }
+
+    /// The `z_min` face followed by the `z_max` face, each in the order of [`Rect::corners`].
+    #[inline]
+    pub fn corners(&self) -> [Vector3<T>; 8]
+    where T: Add<Output = T> + Copy {
+        let (x_min, x_max) = (self.get_x_min(), self.get_x_max());
+        let (y_min, y_max) = (self.get_y_min(), self.get_y_max());
+        let (z_min, z_max) = (self.get_z_min(), self.get_z_max());
+
+        [
+            Vector3::new_comp(x_min, y_min, z_min),
+            Vector3::new_comp(x_max, y_min, z_min),
+            Vector3::new_comp(x_max, y_max, z_min),
+            Vector3::new_comp(x_min, y_max, z_min),
+            Vector3::new_comp(x_min, y_min, z_max),
+            Vector3::new_comp(x_max, y_min, z_max),
+            Vector3::new_comp(x_max, y_max, z_max),
+            Vector3::new_comp(x_min, y_max, z_max),
+        ]
+    }
+
+    /// Distance from `point` to the cube, zero when it lies inside. See
+    /// [`Cube::signed_distance`] for the penetration depth.
+    #[inline]
+    pub fn distance_to(&self, point: Vector3<T>) -> T
+    where T: Real {
+        self.signed_distance(point).max(T::zero())
+    }
+
+    /// Splits into eight equal octants, ordered like [`Cube::corners`]: each child holds the
+    /// corner of the same index.
+    #[inline]
+    pub fn subdivide(&self) -> [Cube<T>; 8]
+    where T: Real {
+        let half = self.get_size() / (T::one() + T::one());
+        let position = self.get_position();
+
+        [(0, 0, 0), (1, 0, 0), (1, 1, 0), (0, 1, 0), (0, 0, 1), (1, 0, 1), (1, 1, 1), (0, 1, 1)].map(|(x, y, z)| {
+            let offset = Vector3::new_comp(
+                half.x * T::from(x).unwrap(),
+                half.y * T::from(y).unwrap(),
+                half.z * T::from(z).unwrap());
+            Cube::new_vectors(position + offset, half)
+        })
+    }
+}
+
+/// Reads the array as `[x, y, z, width, height, depth]`.
+impl<T> From<[T; 6]> for Cube<T>
+where T: Copy {
+    #[inline]
+    fn from(array: [T; 6]) -> Self {
+        Self::new(array[0], array[1], array[2], array[3], array[4], array[5])
+    }
+}
+
+/// Flattens into `[x, y, z, width, height, depth]`.
+impl<T> From<Cube<T>> for [T; 6]
+where T: Copy {
+    #[inline]
+    fn from(shape: Cube<T>) -> Self {
+        [shape.x, shape.y, shape.z, shape.width, shape.height, shape.depth]
+    }
 }
 
 impl<T> From<Area3D<T>> for Cube<T>
@@ -1233,7 +1975,7 @@ where T: Real {
 
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
-struct Area3D<T> {
+pub struct Area3D<T> {
     pub lower_left: Vector3<T>,
     pub upper_right: Vector3<T>,
 }
@@ -1249,8 +1991,21 @@ impl<T> Area3D<T> {
         Area3D { lower_left, upper_right }
     }
 
+    /// Same as [`Area3D::new_vectors`], named to match [`Bounds3D::from_min_max`].
     #[inline]
-    pub fn set(&mut self, lower_left_x: T, lower_left_y: T, lower_left_z: T, upper_right_x: T, upper_right_y: T, upper_right_z: T) {
+    pub fn from_min_max(min: Vector3<T>, max: Vector3<T>) -> Self {
+        Self::new_vectors(min, max)
+    }
+
+    #[inline]
+    pub fn from_center_size(center: Vector3<T>, size: Vector3<T>) -> Self
+    where T: Real {
+        let half_size = size / (T::one() + T::one());
+        Self::new_vectors(center - half_size, center + half_size)
+    }
+
+    #[inline]
+    pub fn set(&mut self, lower_left_x: T, lower_left_y: T, lower_left_z: T, upper_right_x: T, upper_right_y: T, upper_right_z: T) {
         self.lower_left.x = lower_left_x;
         self.lower_left.y = lower_left_y;
         self.lower_left.z = lower_left_z;
@@ -1422,23 +2177,13 @@ impl<T> Area3D<T> {
     #[inline]
     pub fn contains(&self, point: Vector3<T>) -> bool
     where T: PartialOrd + Copy {
-        point.x >= self.lower_left.x &&
-        point.x <= self.upper_right.x &&
-        point.y >= self.lower_left.y &&
-        point.y <= self.upper_right.y &&
-        point.z >= self.lower_left.z &&
-        point.z <= self.upper_right.z
+        AabbN::from(*self).contains(point.into())
     }
 
     #[inline]
     pub fn overlaps(&self, other: &Area3D<T>) -> bool
     where T: PartialOrd + Copy {
-        self.lower_left.x < other.upper_right.x &&
-        self.upper_right.x > other.lower_left.x &&
-        self.lower_left.y < other.upper_right.y &&
-        self.upper_right.y > other.lower_left.y &&
-        self.lower_left.z < other.upper_right.z &&
-        self.upper_right.z > other.lower_left.z
+        AabbN::from(*self).overlaps(&AabbN::from(*other))
     }
 
     #[inline]
@@ -1451,6 +2196,65 @@ impl<T> Area3D<T> {
         self.lower_left.z < bounds.center.z + bounds.extents.z &&
         self.upper_right.z > bounds.center.z - bounds.extents.z
     }
+
+    #[inline]
+    pub fn overlaps_sphere(&self, sphere: &Sphere<T>) -> bool
+    where T: Real {
+        let cube = Cube::from(*self);
+        cube.overlaps_sphere(*sphere)
+    }
+
+    #[inline]
+    pub fn encapsulate(&mut self, point: Vector3<T>)
+    where T: PartialOrd + Copy {
+        self.lower_left = Vector3::min(self.lower_left, point);
+        self.upper_right = Vector3::max(self.upper_right, point);
+    }
+
+    #[inline]
+    pub fn encapsulate_area(&mut self, other: &Area3D<T>)
+    where T: PartialOrd + Copy {
+        self.lower_left = Vector3::min(self.lower_left, other.lower_left);
+        self.upper_right = Vector3::max(self.upper_right, other.upper_right);
+    }
+
+    /// Grows every side by `margin`. Negative margins shrink the box and can leave it empty,
+    /// with a negative size, once they exceed half its size.
+    #[inline]
+    pub fn expanded(&self, margin: T) -> Self
+    where T: Add<Output = T> + Sub<Output = T> + Copy {
+        self.expanded_vector(Vector3::new_comp(margin, margin, margin))
+    }
+
+    #[inline]
+    pub fn expanded_vector(&self, margin: Vector3<T>) -> Self
+    where T: Add<Output = T> + Sub<Output = T> + Copy {
+        Self::new_vectors(self.lower_left - margin, self.upper_right + margin)
+    }
+
+    #[inline]
+    pub fn overlaps_cube(&self, cube: &Cube<T>) -> bool
+    where T: PartialOrd + Add<Output = T> + Sub<Output = T> + Copy {
+        cube.overlaps_area(*self)
+    }
+}
+
+/// Reads the array as `[lower_left_x, lower_left_y, lower_left_z, upper_right_x, upper_right_y, upper_right_z]`.
+impl<T> From<[T; 6]> for Area3D<T>
+where T: Copy {
+    #[inline]
+    fn from(array: [T; 6]) -> Self {
+        Self::new(array[0], array[1], array[2], array[3], array[4], array[5])
+    }
+}
+
+/// Flattens into `[lower_left_x, lower_left_y, lower_left_z, upper_right_x, upper_right_y, upper_right_z]`.
+impl<T> From<Area3D<T>> for [T; 6]
+where T: Copy {
+    #[inline]
+    fn from(shape: Area3D<T>) -> Self {
+        [shape.lower_left.x, shape.lower_left.y, shape.lower_left.z, shape.upper_right.x, shape.upper_right.y, shape.upper_right.z]
+    }
 }
 
 impl<T> From<Bounds3D<T>> for Area3D<T>
@@ -1491,7 +2295,7 @@ where T: Add<Output = T> + Sub<Output = T> + Copy {
 
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
-struct Bounds3D<T> {
+pub struct Bounds3D<T> {
     pub center: Vector3<T>,
     pub extents: Vector3<T>,
 }
@@ -1508,6 +2312,19 @@ impl<T> Bounds3D<T> {
         Bounds3D { center, extents }
     }
 
+    #[inline]
+    pub fn from_min_max(min: Vector3<T>, max: Vector3<T>) -> Self
+    where T: Real {
+        let two = T::one() + T::one();
+        Self::new_vectors((min + max) / two, (max - min) / two)
+    }
+
+    #[inline]
+    pub fn from_center_size(center: Vector3<T>, size: Vector3<T>) -> Self
+    where T: Real {
+        Self::new_vectors(center, size / (T::one() + T::one()))
+    }
+
     #[inline]
     pub fn set(&mut self, center_x: T, center_y: T, center_z: T, extents_x: T, extents_y: T, extents_z: T)
     where T: Copy {
@@ -1657,15 +2474,22 @@ impl<T> Bounds3D<T> {
         self.extents -= half_delta;
     }
 
+    #[inline]
+    pub fn set_size_vector(&mut self, size: Vector3<T>)
+    where T: SubAssign + Copy + Real {
+        self.set_size(size.x, size.y, size.z);
+    }
+
+    /// Inclusive: points on the boundary count as contained, like every other shape.
     #[inline]
     pub fn contains(&self, point: Vector3<T>) -> bool
     where T: Add<Output = T> + Sub<Output = T> + PartialOrd + Copy {
-        self.center.x - self.extents.x < point.x &&
-        self.center.x + self.extents.x > point.x &&
-        self.center.y - self.extents.y < point.y &&
-        self.center.y + self.extents.y > point.y &&
-        self.center.z - self.extents.z < point.z &&
-        self.center.z + self.extents.z > point.z
+        self.center.x - self.extents.x <= point.x &&
+        self.center.x + self.extents.x >= point.x &&
+        self.center.y - self.extents.y <= point.y &&
+        self.center.y + self.extents.y >= point.y &&
+        self.center.z - self.extents.z <= point.z &&
+        self.center.z + self.extents.z >= point.z
     }
 
     #[inline]
@@ -1689,6 +2513,87 @@ impl<T> Bounds3D<T> {
         self.center.z - self.extents.z < area.get_z_max() &&
         self.center.z + self.extents.z > area.get_z_min()
     }
+
+    #[inline]
+    pub fn overlaps_sphere(&self, sphere: &Sphere<T>) -> bool
+    where T: Real {
+        let cube = Cube::from(*self);
+        cube.overlaps_sphere(*sphere)
+    }
+
+    #[inline]
+    pub fn encapsulate(&mut self, point: Vector3<T>)
+    where T: Real {
+        self.encapsulate_min_max(point, point);
+    }
+
+    #[inline]
+    pub fn encapsulate_bounds(&mut self, other: &Bounds3D<T>)
+    where T: Real {
+        self.encapsulate_min_max(other.center - other.extents, other.center + other.extents);
+    }
+
+    #[inline]
+    fn encapsulate_min_max(&mut self, min: Vector3<T>, max: Vector3<T>)
+    where T: Real {
+        let min = Vector3::min(self.center - self.extents, min);
+        let max = Vector3::max(self.center + self.extents, max);
+        let two = T::one() + T::one();
+        self.center = (min + max) / two;
+        self.extents = (max - min) / two;
+    }
+
+    /// Grows every side by `margin`. Negative margins shrink the box and can leave it empty,
+    /// with a negative size, once they exceed the extents.
+    #[inline]
+    pub fn expanded(&self, margin: T) -> Self
+    where T: Add<Output = T> + Copy {
+        self.expanded_vector(Vector3::new_comp(margin, margin, margin))
+    }
+
+    #[inline]
+    pub fn expanded_vector(&self, margin: Vector3<T>) -> Self
+    where T: Add<Output = T> + Copy {
+        Self::new_vectors(self.center, self.extents + margin)
+    }
+
+    #[inline]
+    pub fn overlaps_cube(&self, cube: &Cube<T>) -> bool
+    where T: PartialOrd + Real {
+        cube.overlaps_bounds(*self)
+    }
+
+    /// The axis-aligned bounds enclosing all eight corners after transforming them by `matrix`,
+    /// used here as a linear 3D transform such as [`Matrix3::from_axis_angle`].
+    /// [`Matrix3::from_rotation`] and [`Matrix3::from_scale`] are also valid, as maps that keep z,
+    /// but [`Matrix3::from_translation`] shears x and y by z instead of translating.
+    #[inline]
+    pub fn transformed(&self, matrix: &Matrix3<T>) -> Self
+    where T: Real {
+        let corners = Cube::from(*self).corners().map(|corner| *matrix * corner);
+        let min = corners.iter().fold(corners[0], |min, corner| Vector3::min(min, *corner));
+        let max = corners.iter().fold(corners[0], |max, corner| Vector3::max(max, *corner));
+        let two = T::one() + T::one();
+        Self::new_vectors((min + max) / two, (max - min) / two)
+    }
+}
+
+/// Reads the array as `[center_x, center_y, center_z, extents_x, extents_y, extents_z]`.
+impl<T> From<[T; 6]> for Bounds3D<T>
+where T: Copy {
+    #[inline]
+    fn from(array: [T; 6]) -> Self {
+        Self::new(array[0], array[1], array[2], array[3], array[4], array[5])
+    }
+}
+
+/// Flattens into `[center_x, center_y, center_z, extents_x, extents_y, extents_z]`.
+impl<T> From<Bounds3D<T>> for [T; 6]
+where T: Copy {
+    #[inline]
+    fn from(shape: Bounds3D<T>) -> Self {
+        [shape.center.x, shape.center.y, shape.center.z, shape.extents.x, shape.extents.y, shape.extents.z]
+    }
 }
 
 impl<T> From<Area3D<T>> for Bounds3D<T>
@@ -1700,8 +2605,8 @@ where T: Real {
             area.get_center().y,
             area.get_center().z,
             area.get_width() / (T::one() + T::one()),
-            area.get_width() / (T::one() + T::one()),
-            area.get_width() / (T::one() + T::one()))
+            area.get_height() / (T::one() + T::one()),
+            area.get_depth() / (T::one() + T::one()))
     }
 }
 
@@ -1728,7 +2633,7 @@ where T: Real {
             area.get_center().y,
             T::zero(),
             area.get_width() / (T::one() + T::one()),
-            area.get_width() / (T::one() + T::one()),
+            area.get_height() / (T::one() + T::one()),
             T::zero())
     }
 }
@@ -1756,15 +2661,15 @@ where T: Real {
             area.get_center().y,
             area.get_center().z,
             area.get_width() / (T::one() + T::one()),
-            area.get_width() / (T::one() + T::one()),
-            area.get_width() / (T::one() + T::one()))
+            area.get_height() / (T::one() + T::one()),
+            area.get_depth() / (T::one() + T::one()))
     }
 }
 
 
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
-struct Sphere<T> {
+pub struct Sphere<T> {
     pub center: Vector3<T>,
     pub radius: T,
 }
@@ -1795,19 +2700,19 @@ impl<T> Sphere<T> {
     #[inline]
     pub fn get_circumference(&self) -> T
     where T: Real + Pi<Output = T> {
-        self.radius * (T::one() + T::one()) * T::pi()
+        self.radius * T::tau()
     }
 
     #[inline]
     pub fn set_circumference(&mut self, circumference: T)
     where T: Real + Pi<Output = T> {
-        self.radius = circumference / ((T::one() + T::one()) * T::pi());
+        self.radius = circumference / T::tau();
     }
 
     #[inline]
     pub fn get_area(&self) -> T
     where T: Real + Pi<Output = T> {
-        (T::one() + T::one() + T::one() + T::one()) * T::pi() * self.radius * (T::one() + T::one())
+        (T::one() + T::one() + T::one() + T::one()) * T::pi() * self.radius * self.radius
     }
 
     #[inline]
@@ -1844,6 +2749,132 @@ impl<T> Sphere<T> {
         let radius_sum = self.radius + other.radius;
         distance_squared < radius_sum * radius_sum
     }
+
+    #[inline]
+    pub fn scaled(&self, factor: T) -> Self
+    where T: Mul<Output = T> + Copy {
+        Self::new_vector(self.center, self.radius * factor)
+    }
+
+    #[inline]
+    pub fn approx_eq(&self, other: &Sphere<T>, epsilon: T) -> bool
+    where T: Real {
+        self.center.approx_eq(other.center, epsilon) && (self.radius - other.radius).abs() <= epsilon
+    }
+
+    /// Nearest point on the surface. A `point` at the center has no direction, so the
+    /// point on the surface along [`Vector3::right`] is returned.
+    #[inline]
+    pub fn closest_point(&self, point: Vector3<T>) -> Vector3<T>
+    where T: Real {
+        let delta = point - self.center;
+        let distance = delta.magnitude();
+
+        if distance == T::zero() {
+            return self.center + Vector3::right() * self.radius;
+        }
+
+        self.center + delta * (self.radius / distance)
+    }
+
+    /// Signed distance to the surface, negative inside.
+    #[inline]
+    pub fn distance_to_point(&self, point: Vector3<T>) -> T
+    where T: Real {
+        Vector3::distance(point, self.center) - self.radius
+    }
+
+    /// A sphere centered on the average of `points` that encloses all of them.
+    /// It is cheap to compute but not the minimal enclosing sphere. The radius is padded
+    /// by a relative epsilon so rounding never leaves one of `points` outside.
+    #[inline]
+    pub fn bounding(points: &[Vector3<T>]) -> Option<Self>
+    where T: Real {
+        let center = Vector3::average(points.iter().copied())?;
+        let radius = points.iter()
+            .fold(T::zero(), |radius, point| radius.max(Vector3::distance(center, *point)));
+
+        Some(Self::new_vector(center, radius * (T::one() + T::epsilon())))
+    }
+
+    /// Whether `other` lies entirely inside `self`, touching the boundary included.
+    #[inline]
+    pub fn contains_sphere(&self, other: &Sphere<T>) -> bool
+    where T: Real {
+        Vector3::distance(self.center, other.center) + other.radius <= self.radius
+    }
+
+    /// Whether the surfaces touch at a single point within `epsilon`, either from the outside
+    /// or with one sphere inside the other. Coincident spheres are not tangent.
+    #[inline]
+    pub fn is_tangent_to(&self, other: &Sphere<T>, epsilon: T) -> bool
+    where T: Real {
+        let distance = Vector3::distance(self.center, other.center);
+        let external = (distance - (self.radius + other.radius)).abs() <= epsilon;
+        let internal = distance > epsilon && (distance - (self.radius - other.radius).abs()).abs() <= epsilon;
+        external || internal
+    }
+
+    #[inline]
+    pub fn overlaps_cube(&self, cube: &Cube<T>) -> bool
+    where T: Real {
+        cube.overlaps_sphere(*self)
+    }
+
+    #[inline]
+    pub fn overlaps_area(&self, area: &Area3D<T>) -> bool
+    where T: Real {
+        area.overlaps_sphere(self)
+    }
+
+    #[inline]
+    pub fn overlaps_bounds(&self, bounds: &Bounds3D<T>) -> bool
+    where T: Real {
+        bounds.overlaps_sphere(self)
+    }
+
+    /// Point on the surface in spherical coordinates: `theta` is the polar angle from the
+    /// positive z axis and `phi` the azimuth from the positive x axis toward the positive y axis.
+    #[inline]
+    pub fn point_on_surface(&self, theta: T, phi: T) -> Vector3<T>
+    where T: Real {
+        let (sin_theta, cos_theta) = theta.sin_cos();
+        let (sin_phi, cos_phi) = phi.sin_cos();
+        self.center + Vector3::new_comp(sin_theta * cos_phi, sin_theta * sin_phi, cos_theta) * self.radius
+    }
+
+    /// Gap between the two surfaces. Never negative: overlapping spheres return zero
+    /// rather than a penetration depth.
+    #[inline]
+    pub fn distance_to(&self, other: &Sphere<T>) -> T
+    where T: Real {
+        (Vector3::distance(self.center, other.center) - self.radius - other.radius).max(T::zero())
+    }
+
+    /// Gap between the surface and `cube`, zero when they overlap.
+    #[inline]
+    pub fn distance_to_cube(&self, cube: &Cube<T>) -> T
+    where T: Real {
+        (cube.signed_distance(self.center) - self.radius).max(T::zero())
+    }
+}
+
+/// Reads the array as `[center_x, center_y, center_z, radius]`.
+impl<T> From<[T; 4]> for Sphere<T>
+where T: Copy {
+    #[inline]
+    fn from(array: [T; 4]) -> Self {
+        Self::new(array[0], array[1], array[2], array[3])
+    }
+}
+
+/// Flattens into `[center_x, center_y, center_z, radius]`.
+impl<T> From<Sphere<T>> for [T; 4]
+where T: Copy {
+    #[inline]
+    fn from(shape: Sphere<T>) -> Self {
+        [shape.center.x, shape.center.y, shape.center.z, shape.radius]
+    }
 }
 
 impl<T> From<Circle<T>> for Sphere<T>
@@ -1857,7 +2888,7 @@ where T: Real {
 
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
-struct Line3D<T> {
+pub struct Line3D<T> {
     pub start: Vector3<T>,
     pub end: Vector3<T>,
 }
@@ -1958,21 +2989,154 @@ impl<T> Line3D<T> {
         self.end = center + delta / (T::one() + T::one());
     }
 
-    // #[inline]
-    // pub fn intersects(&self, other: &Line3D<T>) -> bool {
-    //     todo!()
-    // }
+    #[inline]
+    pub fn closest_point(&self, point: Vector3<T>) -> Vector3<T>
+    where T: Real {
+        let delta = self.get_delta();
+        let sqr_length = delta.sqr_magnitude();
+
+        if sqr_length == T::zero() {
+            return self.start;
+        }
+
+        let t = Vector3::dot(point - self.start, delta) / sqr_length;
+        self.start + delta * t.max(T::zero()).min(T::one())
+    }
+
+    #[inline]
+    pub fn distance_to_point(&self, point: Vector3<T>) -> T
+    where T: Real {
+        Vector3::distance(self.closest_point(point), point)
+    }
+
+    // Closest point of approach between two segments, see Ericson's Real-Time Collision Detection 5.1.9
+    #[inline]
+    pub fn closest_points(&self, other: &Line3D<T>) -> (Vector3<T>, Vector3<T>, T)
+    where T: Real {
+        let zero = T::zero();
+        let one = T::one();
+
+        let d1 = self.get_delta();
+        let d2 = other.get_delta();
+        let r = self.start - other.start;
+        let a = d1.sqr_magnitude();
+        let e = d2.sqr_magnitude();
+        let f = Vector3::dot(d2, r);
+
+        let (s, t) = if a <= T::epsilon() && e <= T::epsilon() {
+            // both segments are points
+            (zero, zero)
+        } else if a <= T::epsilon() {
+            (zero, (f / e).max(zero).min(one))
+        } else {
+            let c = Vector3::dot(d1, r);
+
+            if e <= T::epsilon() {
+                ((-c / a).max(zero).min(one), zero)
+            } else {
+                let b = Vector3::dot(d1, d2);
+                let denom = a * e - b * b;
+
+                // parallel segments have no unique closest pair, so start from self.start
+                let s = if denom > T::epsilon() * a * e {
+                    ((b * f - c * e) / denom).max(zero).min(one)
+                } else {
+                    zero
+                };
+
+                let t = (b * s + f) / e;
+
+                if t < zero {
+                    ((-c / a).max(zero).min(one), zero)
+                } else if t > one {
+                    (((b - c) / a).max(zero).min(one), one)
+                } else {
+                    (s, t)
+                }
+            }
+        };
+
+        let closest_self = self.start + d1 * s;
+        let closest_other = other.start + d2 * t;
+        (closest_self, closest_other, Vector3::distance(closest_self, closest_other))
+    }
+
+    /// Returns the closest points of both segments and their distance when the segments come
+    /// within `tolerance` of each other, and `None` otherwise.
+    #[inline]
+    pub fn intersects(&self, other: &Line3D<T>, tolerance: T) -> Option<(Vector3<T>, Vector3<T>, T)>
+    where T: Real {
+        let closest = self.closest_points(other);
+
+        if closest.2 <= tolerance {
+            Some(closest)
+        } else {
+            None
+        }
+    }
+
+    /// `t` in `[0, 1]` stays on the segment, values outside extrapolate along the line.
+    #[inline]
+    pub fn point_at(&self, t: T) -> Vector3<T>
+    where T: Real {
+        self.start + self.get_delta() * t
+    }
+
+    /// `n` evenly spaced points from `start` to `end`, both included.
+    #[inline]
+    pub fn sample(&self, n: usize) -> Vec<Vector3<T>>
+    where T: Real {
+        if n == 1 {
+            return vec![self.start];
+        }
+
+        let last = T::from(n.saturating_sub(1)).unwrap();
+        (0..n).map(|i| self.point_at(T::from(i).unwrap() / last)).collect()
+    }
+
+    #[inline]
+    pub fn distance_to_segment(&self, other: &Line3D<T>) -> T
+    where T: Real {
+        self.closest_points(other).2
+    }
+}
+
+/// Reads the array as `[start_x, start_y, start_z, end_x, end_y, end_z]`.
+impl<T> From<[T; 6]> for Line3D<T>
+where T: Copy {
+    #[inline]
+    fn from(array: [T; 6]) -> Self {
+        Self::new(array[0], array[1], array[2], array[3], array[4], array[5])
+    }
+}
+
+/// Flattens into `[start_x, start_y, start_z, end_x, end_y, end_z]`.
+impl<T> From<Line3D<T>> for [T; 6]
+where T: Copy {
+    #[inline]
+    fn from(shape: Line3D<T>) -> Self {
+        [shape.start.x, shape.start.y, shape.start.z, shape.end.x, shape.end.y, shape.end.z]
+    }
+}
+
+impl<T> From<Line2D<T>> for Line3D<T>
+where T: Real {
+    #[inline]
+    fn from(line: Line2D<T>) -> Self {
+        Line3D::new_vectors(Vector3::from(line.start), Vector3::from(line.end))
+    }
 }
 
 
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
-struct Area4D<T> {
+pub struct Area4D<T> {
     pub lower_left: Vector4<T>,
     pub upper_right: Vector4<T>,
 }
 
 impl<T> Area4D<T> {
+    #[allow(clippy::too_many_arguments)]
     #[inline]
     pub fn new(lower_left_x: T, lower_left_y: T, lower_left_z: T, lower_left_w: T, upper_right_x: T, upper_right_y: T, upper_right_z: T, upper_right_w: T) -> Self {
         Self::new_vectors(
@@ -1985,6 +3149,7 @@ impl<T> Area4D<T> {
         Self { lower_left, upper_right }
     }
 
+    #[allow(clippy::too_many_arguments)]
     #[inline]
     pub fn set(&mut self, lower_left_x: T, lower_left_y: T, lower_left_z: T, lower_left_w: T, upper_right_x: T, upper_right_y: T, upper_right_z: T, upper_right_w: T) {
         self.lower_left.x = lower_left_x;
@@ -2102,7 +3267,7 @@ impl<T> Area4D<T> {
     #[inline]
     pub fn get_width(&self) -> T
     where T: Sub<Output = T> + Copy {
-        self.upper_right.w - self.lower_left.w
+        self.upper_right.x - self.lower_left.x
     }
 
     #[inline]
@@ -2206,25 +3371,13 @@ impl<T> Area4D<T> {
     #[inline]
     pub fn contains(&self, point: Vector4<T>) -> bool
     where T: PartialOrd + Copy {
-        point.x >= self.lower_left.x &&
-        point.x <= self.upper_right.x &&
-        point.y >= self.lower_left.y &&
-        point.y <= self.upper_right.y &&
-        point.z >= self.lower_left.z &&
-        point.z <= self.upper_right.z
+        AabbN::from(*self).contains(point.into())
     }
 
     #[inline]
     pub fn overlaps(&self, other: &Area4D<T>) -> bool
     where T: PartialOrd + Copy {
-        self.lower_left.x < other.upper_right.x &&
-        self.upper_right.x > other.lower_left.x &&
-        self.lower_left.y < other.upper_right.y &&
-        self.upper_right.y > other.lower_left.y &&
-        self.lower_left.z < other.upper_right.z &&
-        self.upper_right.z > other.lower_left.z &&
-        self.lower_left.w < other.upper_right.w &&
-        self.upper_right.w > other.lower_left.w
+        AabbN::from(*self).overlaps(&AabbN::from(*other))
     }
 
     #[inline]
@@ -2239,6 +3392,26 @@ impl<T> Area4D<T> {
         self.lower_left.w < bounds.center.w + bounds.extents.w &&
         self.upper_right.w > bounds.center.w - bounds.extents.w
     }
+
+    #[inline]
+    pub fn encapsulate(&mut self, point: Vector4<T>)
+    where T: PartialOrd + Copy {
+        self.lower_left = Vector4::min(self.lower_left, point);
+        self.upper_right = Vector4::max(self.upper_right, point);
+    }
+
+    #[inline]
+    pub fn encapsulate_area(&mut self, other: &Area4D<T>)
+    where T: PartialOrd + Copy {
+        self.lower_left = Vector4::min(self.lower_left, other.lower_left);
+        self.upper_right = Vector4::max(self.upper_right, other.upper_right);
+    }
+
+    #[inline]
+    pub fn overlaps_hypersphere(&self, hypersphere: &HyperSphere<T>) -> bool
+    where T: Real {
+        hypersphere.overlaps_area(self)
+    }
 }
 
 impl<T> From<Bounds4D<T>> for Area4D<T>
@@ -2260,12 +3433,13 @@ where T: Add<Output = T> + Sub<Output = T> + Copy {
 
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
-struct Bounds4D<T> {
+pub struct Bounds4D<T> {
     pub center: Vector4<T>,
     pub extents: Vector4<T>,
 }
 
 impl<T> Bounds4D<T> {
+    #[allow(clippy::too_many_arguments)]
     #[inline]
     pub fn new(center_x: T, center_y: T, center_z: T, center_w: T, extents_x: T, extents_y: T, extents_z: T, extents_w: T) -> Self
     where T: Copy {
@@ -2279,6 +3453,7 @@ impl<T> Bounds4D<T> {
         Self { center, extents }
     }
 
+    #[allow(clippy::too_many_arguments)]
     #[inline]
     pub fn set(&mut self, center_x: T, center_y: T, center_z: T, center_w: T, extents_x: T, extents_y: T, extents_z: T, extents_w: T)
     where T: Copy {
@@ -2473,17 +3648,24 @@ impl<T> Bounds4D<T> {
         self.extents -= half_delta;
     }
 
+    #[inline]
+    pub fn set_size_vector(&mut self, size: Vector4<T>)
+    where T: SubAssign + Copy + Real {
+        self.set_size(size.x, size.y, size.z, size.w);
+    }
+
+    /// Inclusive: points on the boundary count as contained, like every other shape.
     #[inline]
     pub fn contains(&self, point: Vector4<T>) -> bool
     where T: Add<Output = T> + Sub<Output = T> + PartialOrd + Copy {
-        self.center.x - self.extents.x < point.x &&
-        self.center.x + self.extents.x > point.x &&
-        self.center.y - self.extents.y < point.y &&
-        self.center.y + self.extents.y > point.y &&
-        self.center.z - self.extents.z < point.z &&
-        self.center.z + self.extents.z > point.z &&
-        self.center.w - self.extents.w < point.w &&
-        self.center.w + self.extents.w > point.w
+        self.center.x - self.extents.x <= point.x &&
+        self.center.x + self.extents.x >= point.x &&
+        self.center.y - self.extents.y <= point.y &&
+        self.center.y + self.extents.y >= point.y &&
+        self.center.z - self.extents.z <= point.z &&
+        self.center.z + self.extents.z >= point.z &&
+        self.center.w - self.extents.w <= point.w &&
+        self.center.w + self.extents.w >= point.w
     }
 
     #[inline]
@@ -2511,28 +3693,56 @@ impl<T> Bounds4D<T> {
         self.center.w - self.extents.w < area.get_w_max() &&
         self.center.w + self.extents.w > area.get_w_min()
     }
-}
 
-impl<T> From<Area4D<T>> for Bounds4D<T>
-where T: Real {
     #[inline]
-    fn from(area: Area4D<T>) -> Self {
-        Self::new(
-            area.get_center().x,
-            area.get_center().y,
-            area.get_center().z,
-            area.get_center().w,
-            area.get_width() / (T::one() + T::one()),
-            area.get_width() / (T::one() + T::one()),
-            area.get_width() / (T::one() + T::one()),
-            area.get_width() / (T::one() + T::one()))
+    pub fn encapsulate(&mut self, point: Vector4<T>)
+    where T: Real {
+        self.encapsulate_min_max(point, point);
+    }
+
+    #[inline]
+    pub fn encapsulate_bounds(&mut self, other: &Bounds4D<T>)
+    where T: Real {
+        self.encapsulate_min_max(other.center - other.extents, other.center + other.extents);
+    }
+
+    #[inline]
+    fn encapsulate_min_max(&mut self, min: Vector4<T>, max: Vector4<T>)
+    where T: Real {
+        let min = Vector4::min(self.center - self.extents, min);
+        let max = Vector4::max(self.center + self.extents, max);
+        let two = T::one() + T::one();
+        self.center = (min + max) / two;
+        self.extents = (max - min) / two;
+    }
+
+    #[inline]
+    pub fn overlaps_hypersphere(&self, hypersphere: &HyperSphere<T>) -> bool
+    where T: Real {
+        hypersphere.overlaps_bounds(self)
+    }
+}
+
+impl<T> From<Area4D<T>> for Bounds4D<T>
+where T: Real {
+    #[inline]
+    fn from(area: Area4D<T>) -> Self {
+        Self::new(
+            area.get_center().x,
+            area.get_center().y,
+            area.get_center().z,
+            area.get_center().w,
+            area.get_width() / (T::one() + T::one()),
+            area.get_height() / (T::one() + T::one()),
+            area.get_depth() / (T::one() + T::one()),
+            area.get_hyper_depth() / (T::one() + T::one()))
     }
 }
 
 
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
-struct HyperSphere<T> {
+pub struct HyperSphere<T> {
     pub center: Vector4<T>,
     pub radius: T,
 }
@@ -2567,9 +3777,9 @@ impl<T> HyperSphere<T> {
     }
 
     #[inline]
-    pub fn set_surface_volume(&mut self, area: T)
+    pub fn set_surface_volume(&mut self, surface_volume: T)
     where T: Real + Pi<Output = T> {
-        self.radius = (area / ((T::one() + T::one()) * T::pi() * T::pi())).cbrt();
+        self.radius = (surface_volume / ((T::one() + T::one()) * T::pi() * T::pi())).cbrt();
     }
 
     #[inline]
@@ -2579,9 +3789,9 @@ impl<T> HyperSphere<T> {
     }
 
     #[inline]
-    pub fn set_volume(&mut self, area: T)
+    pub fn set_volume(&mut self, volume: T)
     where T: Real + Pi<Output = T> {
-        self.radius = (area / ((T::one() + T::one() + T::one() + T::one()) / (T::one() + T::one() + T::one())) * T::pi()).cbrt();
+        self.radius = (volume * (T::one() + T::one()) / (T::pi() * T::pi())).sqrt().sqrt();
     }
 
     #[inline]
@@ -2600,17 +3810,32 @@ impl<T> HyperSphere<T> {
         let radius_sum = self.radius + other.radius;
         distance_squared < radius_sum * radius_sum
     }
+
+    #[inline]
+    pub fn overlaps_area(&self, area: &Area4D<T>) -> bool
+    where T: Real {
+        let closest = self.center.clamp(area.lower_left, area.upper_right);
+        (closest - self.center).sqr_magnitude() <= self.radius * self.radius
+    }
+
+    #[inline]
+    pub fn overlaps_bounds(&self, bounds: &Bounds4D<T>) -> bool
+    where T: Real {
+        let closest = self.center.clamp(bounds.center - bounds.extents, bounds.center + bounds.extents);
+        (closest - self.center).sqr_magnitude() <= self.radius * self.radius
+    }
 }
 
 
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
-struct Line4D<T> {
+pub struct Line4D<T> {
     pub start: Vector4<T>,
     pub end: Vector4<T>,
 }
 
 impl<T> Line4D<T> {
+    #[allow(clippy::too_many_arguments)]
     #[inline]
     pub fn new(start_x: T, start_y: T, start_z: T, start_w: T, end_x: T, end_y: T, end_z: T, end_w: T) -> Self {
         Self::new_vectors(
@@ -2623,6 +3848,7 @@ impl<T> Line4D<T> {
         Self { start, end, }
     }
 
+    #[allow(clippy::too_many_arguments)]
     #[inline]
     pub fn set(&mut self, start_x: T, start_y: T, start_z: T, start_w: T, end_x: T, end_y: T, end_z: T, end_w: T) {
         self.start.x = start_x;
@@ -2714,4 +3940,989 @@ impl<T> Line4D<T> {
     // pub fn intersects(&self, other: &Line3D<T>) -> bool {
     //     todo!()
     // }
+
+    /// `t` in `[0, 1]` stays on the segment, values outside extrapolate along the line.
+    #[inline]
+    pub fn point_at(&self, t: T) -> Vector4<T>
+    where T: Real {
+        self.start + self.get_delta() * t
+    }
+
+    /// `n` evenly spaced points from `start` to `end`, both included.
+    #[inline]
+    pub fn sample(&self, n: usize) -> Vec<Vector4<T>>
+    where T: Real {
+        if n == 1 {
+            return vec![self.start];
+        }
+
+        let last = T::from(n.saturating_sub(1)).unwrap();
+        (0..n).map(|i| self.point_at(T::from(i).unwrap() / last)).collect()
+    }
+}
+
+impl<T> From<Line2D<T>> for Line4D<T>
+where T: Real {
+    #[inline]
+    fn from(line: Line2D<T>) -> Self {
+        Line4D::new_vectors(Vector4::from(line.start), Vector4::from(line.end))
+    }
+}
+
+impl<T> From<Line3D<T>> for Line4D<T>
+where T: Real {
+    #[inline]
+    fn from(line: Line3D<T>) -> Self {
+        Line4D::new_vectors(Vector4::from(line.start), Vector4::from(line.end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn circle_scaled() {
+        let circle = Circle::new(1.0, 2.0, 3.0);
+        let scaled = circle.scaled(2.0);
+        assert_eq!(scaled.center, circle.center);
+        assert_eq!(scaled.radius, 6.0);
+
+        let sphere = Sphere::new(1.0, 2.0, 3.0, 4.0).scaled(0.5);
+        assert_eq!(sphere.radius, 2.0);
+    }
+
+    #[test]
+    fn bounds2d_overlaps_circle() {
+        let bounds = Bounds2D::new(0.0, 0.0, 1.0, 1.0);
+        assert!(bounds.overlaps_circle(&Circle::new(1.5, 0.0, 1.0)));
+        assert!(!bounds.overlaps_circle(&Circle::new(5.0, 5.0, 1.0)));
+    }
+
+    #[test]
+    fn cube_overlaps_sphere() {
+        let cube = Cube::new(0.0, 0.0, 0.0, 1.0, 1.0, 1.0);
+
+        let touching_corner = Sphere::new(2.0, 2.0, 2.0, 1.75);
+        assert!(cube.overlaps_sphere(touching_corner));
+
+        let missing_corner = Sphere::new(2.0, 2.0, 2.0, 1.7);
+        assert!(!cube.overlaps_sphere(missing_corner));
+
+        let inside = Sphere::new(0.5, 0.5, 0.5, 0.1);
+        assert!(cube.overlaps_sphere(inside));
+
+        let outside = Sphere::new(3.0, 0.5, 0.5, 1.0);
+        assert!(!cube.overlaps_sphere(outside));
+    }
+
+    #[test]
+    fn bounds3d_and_area3d_overlap_sphere() {
+        let bounds = Bounds3D::new(0.0, 0.0, 0.0, 1.0, 1.0, 1.0);
+        let area = Area3D::from(bounds);
+
+        let near = Sphere::new(1.5, 0.0, 0.0, 1.0);
+        let far = Sphere::new(0.0, 0.0, 5.0, 1.0);
+
+        assert!(bounds.overlaps_sphere(&near));
+        assert!(!bounds.overlaps_sphere(&far));
+        assert!(area.overlaps_sphere(&near));
+        assert!(!area.overlaps_sphere(&far));
+    }
+
+    #[test]
+    fn line3d_intersects_crossing() {
+        let a = Line3D::new(-1.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+        let b = Line3D::new(0.0, -1.0, 0.0, 0.0, 1.0, 0.0);
+
+        let (point_a, point_b, distance) = a.intersects(&b, 1e-9).unwrap();
+        assert_eq!(point_a, Vector3::new_comp(0.0, 0.0, 0.0));
+        assert_eq!(point_b, Vector3::new_comp(0.0, 0.0, 0.0));
+        assert_eq!(distance, 0.0);
+    }
+
+    #[test]
+    fn line3d_intersects_skew() {
+        let a = Line3D::new(-1.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+        let b = Line3D::new(0.0, -1.0, 1.0, 0.0, 1.0, 1.0);
+
+        assert_eq!(a.intersects(&b, 1e-9), None);
+
+        let (point_a, point_b, distance) = a.closest_points(&b);
+        assert_eq!(point_a, Vector3::new_comp(0.0, 0.0, 0.0));
+        assert_eq!(point_b, Vector3::new_comp(0.0, 0.0, 1.0));
+        assert_eq!(distance, 1.0);
+
+        let c = Line3D::new(2.0, -1.0, 1.0, 2.0, 1.0, 1.0);
+        let (point_a, point_b, _) = a.closest_points(&c);
+        assert_eq!(point_a, Vector3::new_comp(1.0, 0.0, 0.0));
+        assert_eq!(point_b, Vector3::new_comp(2.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn line3d_intersects_parallel_and_degenerate() {
+        let a = Line3D::new(0.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+        let b = Line3D::new(0.0, 1.0, 0.0, 1.0, 1.0, 0.0);
+
+        let (_, _, distance) = a.closest_points(&b);
+        assert_eq!(distance, 1.0);
+        assert_eq!(a.intersects(&b, 0.5), None);
+        assert!(a.intersects(&b, 1.0).is_some());
+
+        let point = Line3D::new(0.5, 2.0, 0.0, 0.5, 2.0, 0.0);
+        let (point_a, _, distance) = a.closest_points(&point);
+        assert_eq!(point_a, Vector3::new_comp(0.5, 0.0, 0.0));
+        assert_eq!(distance, 2.0);
+
+        let (_, _, distance) = point.closest_points(&point);
+        assert_eq!(distance, 0.0);
+    }
+
+    #[test]
+    fn sphere_area() {
+        let mut sphere = Sphere::new(0.0, 0.0, 0.0, 2.0);
+        assert!((sphere.get_area() - 50.265).abs() < 1e-3);
+
+        sphere.set_area(100.0);
+        assert!((sphere.get_area() - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn hyper_sphere_volume_round_trip() {
+        let mut hyper_sphere = HyperSphere::new(0.0, 0.0, 0.0, 0.0, 1.0);
+
+        hyper_sphere.set_volume(42.0);
+        assert!((hyper_sphere.get_volume() - 42.0).abs() < 1e-9);
+
+        hyper_sphere.set_surface_volume(42.0);
+        assert!((hyper_sphere.get_surface_volume() - 42.0).abs() < 1e-9);
+
+        hyper_sphere.radius = 3.0;
+        let volume = hyper_sphere.get_volume();
+        hyper_sphere.set_volume(volume);
+        assert!((hyper_sphere.radius - 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn area4d_size_accessors() {
+        let area = Area4D::new(0.0, 0.0, 0.0, 0.0, 1.0, 2.0, 3.0, 4.0);
+        assert_eq!(area.get_width(), 1.0);
+        assert_eq!(area.get_height(), 2.0);
+        assert_eq!(area.get_depth(), 3.0);
+        assert_eq!(area.get_hyper_depth(), 4.0);
+    }
+
+    #[test]
+    fn area_to_bounds_extents() {
+        let area = Area2D::new(0.0, 0.0, 4.0, 6.0);
+        let bounds = Bounds2D::from(area);
+        assert_eq!(bounds.extents, Vector2::new_comp(2.0, 3.0));
+
+        let bounds = Bounds3D::from(area);
+        assert_eq!(bounds.extents, Vector3::new_comp(2.0, 3.0, 0.0));
+
+        let area = Area3D::new(0.0, 0.0, 0.0, 4.0, 6.0, 8.0);
+        assert_eq!(Bounds2D::from(area).extents, Vector2::new_comp(2.0, 3.0));
+        assert_eq!(Bounds3D::from(area).extents, Vector3::new_comp(2.0, 3.0, 4.0));
+
+        let area = Area4D::new(0.0, 0.0, 0.0, 0.0, 4.0, 6.0, 8.0, 10.0);
+        assert_eq!(Bounds2D::from(area).extents, Vector2::new_comp(2.0, 3.0));
+        assert_eq!(Bounds3D::from(area).extents, Vector3::new_comp(2.0, 3.0, 4.0));
+        assert_eq!(Bounds4D::from(area).extents, Vector4::new_comp(2.0, 3.0, 4.0, 5.0));
+    }
+
+    #[test]
+    fn rect_union() {
+        let a = Rect::new(0.0, 0.0, 1.0, 1.0);
+        let b = Rect::new(3.0, -2.0, 1.0, 1.0);
+        let union = a.union(&b);
+        assert_eq!(union, Rect::new(0.0, -2.0, 4.0, 3.0));
+        assert_eq!(union, b.union(&a));
+        assert!(union.contains(Vector2::new_comp(0.5, 0.5)));
+        assert!(union.contains(Vector2::new_comp(3.5, -1.5)));
+    }
+
+    #[test]
+    fn rect_from_points() {
+        let points = [
+            Vector2::new_comp(4.0, 1.0),
+            Vector2::new_comp(1.0, 3.0),
+            Vector2::new_comp(1.0, 1.0),
+            Vector2::new_comp(4.0, 3.0),
+        ];
+        assert_eq!(Rect::from_points(&points), Some(Rect::new(1.0, 1.0, 3.0, 2.0)));
+        assert_eq!(Rect::from_points(&points[..1]), Some(Rect::new(4.0, 1.0, 0.0, 0.0)));
+        assert_eq!(Rect::<f32>::from_points(&[]), None);
+    }
+
+    #[test]
+    fn rect_normalized() {
+        let rect = Rect::new(2.0, 3.0, -2.0, -1.0);
+        let normalized = rect.normalized();
+        assert_eq!(normalized, Rect::new(0.0, 2.0, 2.0, 1.0));
+        assert_eq!(normalized.normalized(), normalized);
+
+        assert!(normalized.contains(Vector2::new_comp(1.0, 2.5)));
+        assert!(rect.contains(Vector2::new_comp(1.0, 2.5)));
+        assert!(!rect.contains(Vector2::new_comp(3.0, 2.5)));
+        assert!(rect.overlaps(&Rect::new(1.5, 2.5, 1.0, 1.0)));
+        assert!(!rect.overlaps(&Rect::new(2.5, 2.5, 1.0, 1.0)));
+    }
+
+    #[test]
+    fn line2d_closest_point() {
+        let line = Line2D::new(0.0, 0.0, 4.0, 0.0);
+
+        assert_eq!(line.closest_point(Vector2::new_comp(1.0, 3.0)), Vector2::new_comp(1.0, 0.0));
+        assert_eq!(line.distance_to_point(Vector2::new_comp(1.0, 3.0)), 3.0);
+
+        assert_eq!(line.closest_point(Vector2::new_comp(7.0, 4.0)), Vector2::new_comp(4.0, 0.0));
+        assert_eq!(line.distance_to_point(Vector2::new_comp(7.0, 4.0)), 5.0);
+        assert_eq!(line.closest_point(Vector2::new_comp(-2.0, -1.0)), Vector2::new_comp(0.0, 0.0));
+
+        assert_eq!(line.closest_point_on_line(Vector2::new_comp(7.0, 4.0)), Vector2::new_comp(7.0, 0.0));
+        assert_eq!(line.closest_point_on_line(Vector2::new_comp(-2.0, -1.0)), Vector2::new_comp(-2.0, 0.0));
+
+        let point = Line2D::new(1.0, 1.0, 1.0, 1.0);
+        assert_eq!(point.closest_point(Vector2::new_comp(4.0, 5.0)), Vector2::new_comp(1.0, 1.0));
+        assert_eq!(point.distance_to_point(Vector2::new_comp(4.0, 5.0)), 5.0);
+    }
+
+    #[test]
+    fn shapes_approx_eq() {
+        let rect = Rect::new(0.1 + 0.2, 0.0, 1.0, 1.0);
+        assert_ne!(rect, Rect::new(0.3, 0.0, 1.0, 1.0));
+        assert!(rect.approx_eq(&Rect::new(0.3, 0.0, 1.0, 1.0), 1e-9));
+        assert!(!rect.approx_eq(&Rect::new(0.3, 0.0, 1.0, 1.1), 1e-9));
+
+        let circle = Circle::new(0.0, 0.0, 0.1 + 0.2);
+        assert!(circle.approx_eq(&Circle::new(0.0, 0.0, 0.3), 1e-9));
+        assert!(!circle.approx_eq(&Circle::new(0.0, 0.1, 0.3), 1e-9));
+
+        let cube = Cube::new(0.0, 0.0, 0.0, 1.0, 1.0, 0.1 + 0.2);
+        assert!(cube.approx_eq(&Cube::new(0.0, 0.0, 0.0, 1.0, 1.0, 0.3), 1e-9));
+
+        let sphere = Sphere::new(0.0, 0.1 + 0.2, 0.0, 1.0);
+        assert!(sphere.approx_eq(&Sphere::new(0.0, 0.3, 0.0, 1.0), 1e-9));
+        assert!(!sphere.approx_eq(&Sphere::new(0.0, 0.3, 0.0, 2.0), 1e-9));
+    }
+
+    #[test]
+    fn circle_intersects() {
+        let a = Circle::new(0.0, 0.0, 5.0);
+
+        assert_eq!(
+            a.intersects(&Circle::new(8.0, 0.0, 5.0)),
+            CircleIntersection::Two(Vector2::new_comp(4.0, 3.0), Vector2::new_comp(4.0, -3.0)));
+
+        assert_eq!(a.intersects(&Circle::new(0.0, 7.0, 2.0)), CircleIntersection::Tangent(Vector2::new_comp(0.0, 5.0)));
+        assert_eq!(a.intersects(&Circle::new(3.0, 0.0, 2.0)), CircleIntersection::Tangent(Vector2::new_comp(5.0, 0.0)));
+
+        assert_eq!(a.intersects(&Circle::new(20.0, 0.0, 2.0)), CircleIntersection::None);
+        assert_eq!(a.intersects(&Circle::new(1.0, 1.0, 1.0)), CircleIntersection::None);
+        assert_eq!(a.intersects(&Circle::new(0.0, 0.0, 1.0)), CircleIntersection::None);
+        assert_eq!(a.intersects(&a), CircleIntersection::Coincident);
+    }
+
+    #[test]
+    fn line2d_intersect_circle() {
+        let circle = Circle::new(0.0, 0.0, 5.0);
+
+        let chord = Line2D::new(-10.0, 3.0, 10.0, 3.0);
+        assert_eq!(chord.intersect_circle(&circle), Some((Vector2::new_comp(-4.0, 3.0), Some(Vector2::new_comp(4.0, 3.0)))));
+
+        let tangent = Line2D::new(-10.0, 5.0, 10.0, 5.0);
+        assert_eq!(tangent.intersect_circle(&circle), Some((Vector2::new_comp(0.0, 5.0), None)));
+
+        let exiting = Line2D::new(0.0, 3.0, 10.0, 3.0);
+        assert_eq!(exiting.intersect_circle(&circle), Some((Vector2::new_comp(4.0, 3.0), None)));
+
+        let miss = Line2D::new(-10.0, 6.0, 10.0, 6.0);
+        assert_eq!(miss.intersect_circle(&circle), None);
+
+        let short = Line2D::new(6.0, 3.0, 10.0, 3.0);
+        assert_eq!(short.intersect_circle(&circle), None);
+
+        let inside = Line2D::new(-1.0, 0.0, 1.0, 0.0);
+        assert_eq!(inside.intersect_circle(&circle), None);
+    }
+
+    #[test]
+    fn wide_area2d_to_bounds2d() {
+        let area = Area2D::new(-2.0, 1.0, 8.0, 2.0);
+        assert_eq!(area.get_center(), Vector2::new_comp(3.0, 1.5));
+
+        let bounds = Bounds2D::from(area);
+        assert_eq!(bounds.center, Vector2::new_comp(3.0, 1.5));
+        assert_eq!(bounds.extents, Vector2::new_comp(5.0, 0.5));
+        assert_eq!(Area2D::from(bounds), area);
+    }
+
+    #[test]
+    fn line_lifts() {
+        let line = Line2D::new(1.0, 2.0, 3.0, 4.0);
+
+        let lifted = Line3D::from(line);
+        assert_eq!(lifted.start, Vector3::new_comp(1.0, 2.0, 0.0));
+        assert_eq!(lifted.end, Vector3::new_comp(3.0, 4.0, 0.0));
+        assert_eq!(Line2D::from(lifted), line);
+
+        let lifted = Line4D::from(line);
+        assert_eq!(lifted.start, Vector4::new_comp(1.0, 2.0, 0.0, 0.0));
+        assert_eq!(lifted.end, Vector4::new_comp(3.0, 4.0, 0.0, 0.0));
+
+        let lifted = Line4D::from(Line3D::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0));
+        assert_eq!(lifted.start, Vector4::new_comp(1.0, 2.0, 3.0, 0.0));
+        assert_eq!(lifted.end, Vector4::new_comp(4.0, 5.0, 6.0, 0.0));
+    }
+
+    #[test]
+    fn bounds_encapsulate() {
+        let mut bounds = Bounds2D::new(0.0, 0.0, 1.0, 1.0);
+        bounds.encapsulate(Vector2::new_comp(5.0, 0.5));
+        assert_eq!(bounds.get_x_min(), -1.0);
+        assert_eq!(bounds.get_x_max(), 5.0);
+        assert_eq!(bounds.get_y_min(), -1.0);
+        assert_eq!(bounds.get_y_max(), 1.0);
+
+        bounds.encapsulate_bounds(&Bounds2D::new(0.0, -4.0, 1.0, 1.0));
+        assert_eq!(bounds, Bounds2D::new(2.0, -2.0, 3.0, 3.0));
+
+        let mut bounds = Bounds3D::new(0.0, 0.0, 0.0, 1.0, 1.0, 1.0);
+        bounds.encapsulate(Vector3::new_comp(0.0, 0.0, -3.0));
+        assert_eq!(bounds, Bounds3D::new(0.0, 0.0, -1.0, 1.0, 1.0, 2.0));
+
+        let mut bounds = Bounds4D::new(0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0);
+        bounds.encapsulate(Vector4::new_comp(0.0, 0.0, 0.0, 3.0));
+        assert_eq!(bounds, Bounds4D::new(0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0, 2.0));
+    }
+
+    #[test]
+    fn area_encapsulate() {
+        let mut area = Area2D::new(0, 0, 1, 1);
+        area.encapsulate(Vector2::new_comp(-3, 0));
+        assert_eq!(area, Area2D::new(-3, 0, 1, 1));
+
+        area.encapsulate_area(&Area2D::new(0, 0, 2, 5));
+        assert_eq!(area, Area2D::new(-3, 0, 2, 5));
+
+        let mut area = Area3D::new(0, 0, 0, 1, 1, 1);
+        area.encapsulate(Vector3::new_comp(0, 4, 0));
+        assert_eq!(area, Area3D::new(0, 0, 0, 1, 4, 1));
+
+        let mut area = Area4D::new(0, 0, 0, 0, 1, 1, 1, 1);
+        area.encapsulate(Vector4::new_comp(0, 0, 0, -2));
+        assert_eq!(area, Area4D::new(0, 0, 0, -2, 1, 1, 1, 1));
+    }
+
+    #[test]
+    fn bounds_contains_is_inclusive() {
+        let bounds = Bounds2D::new(0.0, 0.0, 1.0, 2.0);
+        assert!(bounds.contains(Vector2::new_comp(1.0, 2.0)));
+        assert!(bounds.contains(Vector2::new_comp(-1.0, -2.0)));
+        assert!(!bounds.contains(Vector2::new_comp(1.0, 2.5)));
+        assert_eq!(bounds.contains(Vector2::new_comp(1.0, 2.0)), Rect::from(bounds).contains(Vector2::new_comp(1.0, 2.0)));
+
+        let bounds = Bounds3D::new(0.0, 0.0, 0.0, 1.0, 1.0, 1.0);
+        assert!(bounds.contains(Vector3::new_comp(1.0, -1.0, 1.0)));
+        assert!(!bounds.contains(Vector3::new_comp(1.0, -1.0, 1.5)));
+
+        let bounds = Bounds4D::new(0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0);
+        assert!(bounds.contains(Vector4::new_comp(1.0, 1.0, 1.0, 1.0)));
+        assert!(!bounds.contains(Vector4::new_comp(1.0, 1.0, 1.0, 1.5)));
+    }
+
+    #[test]
+    fn circle_closest_point_and_distance() {
+        let circle = Circle::new(1.0, 1.0, 2.0);
+
+        assert_eq!(circle.closest_point(Vector2::new_comp(5.0, 1.0)), Vector2::new_comp(3.0, 1.0));
+        assert_eq!(circle.distance_to_point(Vector2::new_comp(5.0, 1.0)), 2.0);
+
+        assert_eq!(circle.closest_point(Vector2::new_comp(1.0, 0.5)), Vector2::new_comp(1.0, -1.0));
+        assert_eq!(circle.distance_to_point(Vector2::new_comp(1.0, 0.5)), -1.5);
+
+        assert_eq!(circle.closest_point(Vector2::new_comp(1.0, 3.0)), Vector2::new_comp(1.0, 3.0));
+        assert_eq!(circle.distance_to_point(Vector2::new_comp(1.0, 3.0)), 0.0);
+
+        assert_eq!(circle.closest_point(circle.center), Vector2::new_comp(3.0, 1.0));
+        assert_eq!(circle.distance_to_point(circle.center), -2.0);
+    }
+
+    #[test]
+    fn sphere_closest_point_and_distance() {
+        let sphere = Sphere::new(0.0, 0.0, 0.0, 1.0);
+
+        assert_eq!(sphere.closest_point(Vector3::new_comp(0.0, 0.0, 4.0)), Vector3::new_comp(0.0, 0.0, 1.0));
+        assert_eq!(sphere.distance_to_point(Vector3::new_comp(0.0, 0.0, 4.0)), 3.0);
+
+        assert_eq!(sphere.closest_point(Vector3::new_comp(0.0, -0.25, 0.0)), Vector3::new_comp(0.0, -1.0, 0.0));
+        assert_eq!(sphere.distance_to_point(Vector3::new_comp(0.0, -0.25, 0.0)), -0.75);
+
+        assert_eq!(sphere.distance_to_point(Vector3::new_comp(1.0, 0.0, 0.0)), 0.0);
+        assert_eq!(sphere.closest_point(sphere.center), Vector3::new_comp(1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn rect_closest_point_and_signed_distance() {
+        let rect = Rect::new(0.0, 0.0, 4.0, 2.0);
+
+        assert_eq!(rect.closest_point(Vector2::new_comp(6.0, 1.0)), Vector2::new_comp(4.0, 1.0));
+        assert_eq!(rect.closest_point(Vector2::new_comp(-1.0, -1.0)), Vector2::new_comp(0.0, 0.0));
+        assert_eq!(rect.closest_point(Vector2::new_comp(1.0, 1.5)), Vector2::new_comp(1.0, 1.5));
+
+        assert_eq!(rect.signed_distance(Vector2::new_comp(1.0, 1.5)), -0.5);
+        assert_eq!(rect.signed_distance(Vector2::new_comp(2.0, 1.0)), -1.0);
+        assert_eq!(rect.signed_distance(Vector2::new_comp(4.0, 1.0)), 0.0);
+        assert_eq!(rect.signed_distance(Vector2::new_comp(7.0, 6.0)), 5.0);
+        assert_eq!(rect.signed_distance(Vector2::new_comp(-1.0, -1.0)), 2.0f64.sqrt());
+
+        let flipped = Rect::new(4.0, 2.0, -4.0, -2.0);
+        assert_eq!(flipped.signed_distance(Vector2::new_comp(7.0, 6.0)), 5.0);
+    }
+
+    #[test]
+    fn cube_closest_point_and_signed_distance() {
+        let cube = Cube::new(-1.0, -1.0, -1.0, 2.0, 2.0, 2.0);
+
+        assert_eq!(cube.closest_point(Vector3::new_comp(0.0, 5.0, 0.5)), Vector3::new_comp(0.0, 1.0, 0.5));
+        assert_eq!(cube.signed_distance(Vector3::new_comp(0.0, 0.0, 0.0)), -1.0);
+        assert_eq!(cube.signed_distance(Vector3::new_comp(0.0, 0.0, 3.0)), 2.0);
+        assert_eq!(cube.signed_distance(Vector3::new_comp(1.0, 1.0, 0.0)), 0.0);
+        assert_eq!(cube.signed_distance(Vector3::new_comp(2.0, 2.0, 2.0)), 3.0f64.sqrt());
+
+        let flipped = Cube::new(2.0, 2.0, 2.0, -2.0, -2.0, -2.0);
+        assert_eq!(flipped.closest_point(Vector3::new_comp(1.0, 1.0, 1.0)), Vector3::new_comp(1.0, 1.0, 1.0));
+        assert_eq!(flipped.closest_point(Vector3::new_comp(1.0, 5.0, -1.0)), Vector3::new_comp(1.0, 2.0, 0.0));
+        assert_eq!(flipped.signed_distance(Vector3::new_comp(1.0, 1.0, 1.0)), -1.0);
+    }
+
+    #[test]
+    fn bounding_circle_and_sphere() {
+        let points = [
+            Vector2::new_comp(0.0, 0.0),
+            Vector2::new_comp(6.0, 0.0),
+            Vector2::new_comp(0.0, 3.0),
+        ];
+        let circle = Circle::bounding(&points).unwrap();
+        assert_eq!(circle.center, Vector2::new_comp(2.0, 1.0));
+        assert!(points.iter().all(|point| circle.contains(*point)));
+        assert_eq!(Circle::<f64>::bounding(&[]), None);
+
+        let points = [
+            Vector3::new_comp(1.0, 0.0, 0.0),
+            Vector3::new_comp(-2.0, 4.0, 1.0),
+            Vector3::new_comp(0.0, -1.0, 5.0),
+        ];
+        let sphere = Sphere::bounding(&points).unwrap();
+        assert!(points.iter().all(|point| sphere.contains(*point)));
+        assert_eq!(Sphere::<f64>::bounding(&[]), None);
+
+        let single = Sphere::bounding(&[Vector3::new_comp(1.0, 2.0, 3.0)]).unwrap();
+        assert_eq!(single, Sphere::new(1.0, 2.0, 3.0, 0.0));
+    }
+
+    #[test]
+    fn boxes_expanded() {
+        let rect = Rect::new(0.0, 0.0, 1.0, 1.0).expanded(1.0);
+        assert_eq!(rect, Rect::new(-1.0, -1.0, 3.0, 3.0));
+        assert_eq!((rect.get_x_min(), rect.get_x_max()), (-1.0, 2.0));
+        assert_eq!(Rect::new(0.0, 0.0, 4.0, 2.0).expanded_vector(Vector2::new_comp(-1.0, 0.5)), Rect::new(1.0, -0.5, 2.0, 3.0));
+        assert_eq!(Rect::new(0.0, 0.0, 1.0, 1.0).expanded(-1.0), Rect::new(1.0, 1.0, -1.0, -1.0));
+
+        assert_eq!(Area2D::new(0.0, 0.0, 1.0, 1.0).expanded(1.0), Area2D::new(-1.0, -1.0, 2.0, 2.0));
+        assert_eq!(Bounds2D::new(0.5, 0.5, 0.5, 0.5).expanded(1.0), Bounds2D::new(0.5, 0.5, 1.5, 1.5));
+
+        assert_eq!(Cube::new(0.0, 0.0, 0.0, 1.0, 1.0, 1.0).expanded(1.0), Cube::new(-1.0, -1.0, -1.0, 3.0, 3.0, 3.0));
+        assert_eq!(
+            Area3D::new(0.0, 0.0, 0.0, 1.0, 1.0, 1.0).expanded_vector(Vector3::new_comp(1.0, 0.0, 2.0)),
+            Area3D::new(-1.0, 0.0, -2.0, 2.0, 1.0, 3.0));
+        assert_eq!(Bounds3D::new(0.0, 0.0, 0.0, 1.0, 1.0, 1.0).expanded(-0.5), Bounds3D::new(0.0, 0.0, 0.0, 0.5, 0.5, 0.5));
+    }
+
+    #[test]
+    fn line_point_at_and_sample() {
+        let line = Line2D::new(1.0, 1.0, 5.0, -3.0);
+        assert_eq!(line.point_at(0.0), line.start);
+        assert_eq!(line.point_at(1.0), line.end);
+        assert_eq!(line.point_at(0.5), line.get_center());
+        assert_eq!(line.point_at(1.5), Vector2::new_comp(7.0, -5.0));
+
+        assert_eq!(line.sample(3), vec![line.start, line.get_center(), line.end]);
+        assert_eq!(line.sample(1), vec![line.start]);
+        assert!(line.sample(0).is_empty());
+
+        let line = Line3D::new(0.0, 0.0, 0.0, 0.0, 0.0, 3.0);
+        assert_eq!(line.point_at(1.0), line.end);
+        assert_eq!(line.sample(4).iter().map(|p| p.z).collect::<Vec<_>>(), vec![0.0, 1.0, 2.0, 3.0]);
+
+        let line = Line4D::new(0.0, 0.0, 0.0, 0.0, 2.0, 2.0, 2.0, 2.0);
+        assert_eq!(line.point_at(0.0), line.start);
+        assert_eq!(line.point_at(0.5), Vector4::new_comp(1.0, 1.0, 1.0, 1.0));
+        assert_eq!(line.sample(2), vec![line.start, line.end]);
+    }
+
+    #[test]
+    fn rect_and_cube_corners() {
+        let rect = Rect::new(0.0, 0.0, 1.0, 1.0);
+        let corners = rect.corners();
+        assert_eq!(corners, [
+            Vector2::new_comp(0.0, 0.0),
+            Vector2::new_comp(1.0, 0.0),
+            Vector2::new_comp(1.0, 1.0),
+            Vector2::new_comp(0.0, 1.0),
+        ]);
+
+        let edges = rect.edges();
+        for i in 0..4 {
+            assert_eq!(edges[i].start, corners[i]);
+            assert_eq!(edges[i].end, corners[(i + 1) % 4]);
+            assert_eq!(edges[i].get_length(), 1.0);
+        }
+
+        let corners = Cube::new(1.0, 2.0, 3.0, 1.0, 1.0, 1.0).corners();
+        assert_eq!(corners[0], Vector3::new_comp(1.0, 2.0, 3.0));
+        assert_eq!(corners[2], Vector3::new_comp(2.0, 3.0, 3.0));
+        assert_eq!(corners[6], Vector3::new_comp(2.0, 3.0, 4.0));
+        assert_eq!(corners[7], Vector3::new_comp(1.0, 3.0, 4.0));
+        for i in 0..4 {
+            assert_eq!(corners[i + 4] - corners[i], Vector3::new_comp(0.0, 0.0, 1.0));
+        }
+    }
+
+    #[test]
+    fn area4d_contains_checks_w() {
+        let area = Area4D::new(0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0);
+
+        assert!(area.contains(Vector4::new_comp(0.5, 0.5, 0.5, 0.5)));
+        assert!(!area.contains(Vector4::new_comp(0.5, 0.5, 0.5, 2.0)));
+        assert!(!area.contains(Vector4::new_comp(0.5, 0.5, 0.5, -0.5)));
+    }
+
+    #[test]
+    fn bounds_set_size_non_uniform() {
+        let mut bounds = Bounds2D::new(1.0, 1.0, 1.0, 2.0);
+        bounds.set_size_vector(Vector2::new_comp(6.0, 1.0));
+        assert_eq!(bounds, Bounds2D::new(1.0, 1.0, 3.0, 0.5));
+
+        let mut bounds = Bounds3D::new(0.0, 0.0, 0.0, 1.0, 2.0, 3.0);
+        bounds.set_size(4.0, 2.0, 10.0);
+        assert_eq!(bounds.extents, Vector3::new_comp(2.0, 1.0, 5.0));
+        bounds.set_size_vector(Vector3::new_comp(1.0, 8.0, 0.0));
+        assert_eq!(bounds.extents, Vector3::new_comp(0.5, 4.0, 0.0));
+        assert_eq!(bounds.center, Vector3::new_comp(0.0, 0.0, 0.0));
+
+        let mut bounds = Bounds4D::new(0.0, 0.0, 0.0, 0.0, 1.0, 2.0, 3.0, 4.0);
+        bounds.set_size(2.0, 2.0, 2.0, 2.0);
+        assert_eq!(bounds.extents, Vector4::new_comp(1.0, 1.0, 1.0, 1.0));
+        bounds.set_size_vector(Vector4::new_comp(6.0, 4.0, 2.0, 0.5));
+        assert_eq!(bounds.get_size(), Vector4::new_comp(6.0, 4.0, 2.0, 0.5));
+    }
+
+    #[test]
+    fn line2d_clip_to_rect() {
+        let rect = Rect::new(0.0, 0.0, 4.0, 2.0);
+
+        let crossing = Line2D::new(-2.0, 1.0, 6.0, 1.0);
+        assert_eq!(crossing.clip_to_rect(&rect), Some(Line2D::new(0.0, 1.0, 4.0, 1.0)));
+
+        let diagonal = Line2D::new(-1.0, -1.0, 3.0, 3.0);
+        assert_eq!(diagonal.clip_to_rect(&rect), Some(Line2D::new(0.0, 0.0, 2.0, 2.0)));
+        assert!(diagonal.intersects_rect(&rect));
+
+        let inside = Line2D::new(1.0, 0.5, 3.0, 1.5);
+        assert_eq!(inside.clip_to_rect(&rect), Some(inside));
+
+        let outside = Line2D::new(-1.0, 3.0, 5.0, 3.0);
+        assert_eq!(outside.clip_to_rect(&rect), None);
+        assert!(!outside.intersects_rect(&rect));
+
+        let short = Line2D::new(5.0, 1.0, 6.0, 1.0);
+        assert_eq!(short.clip_to_rect(&rect), None);
+
+        let flipped = Rect::new(4.0, 2.0, -4.0, -2.0);
+        assert_eq!(crossing.clip_to_rect(&flipped), Some(Line2D::new(0.0, 1.0, 4.0, 1.0)));
+    }
+
+    #[test]
+    fn circle_contains_circle_and_tangency() {
+        let big = Circle::new(0.0, 0.0, 5.0);
+        let small = Circle::new(1.0, 1.0, 1.0);
+        assert!(big.contains_circle(&small));
+        assert!(!small.contains_circle(&big));
+        assert!(!big.is_tangent_to(&small, 1e-9));
+
+        let touching = Circle::new(7.0, 0.0, 2.0);
+        assert!(big.is_tangent_to(&touching, 1e-9));
+        assert!(big.overlaps(&Circle::new(6.0, 0.0, 2.0)));
+        assert!(!big.contains_circle(&Circle::new(6.0, 0.0, 2.0)));
+
+        let inner = Circle::new(3.0, 0.0, 2.0);
+        assert!(big.contains_circle(&inner));
+        assert!(big.is_tangent_to(&inner, 1e-9));
+        assert!(inner.is_tangent_to(&big, 1e-9));
+        assert!(!big.is_tangent_to(&big, 1e-9));
+    }
+
+    #[test]
+    fn sphere_contains_sphere_and_tangency() {
+        let big = Sphere::new(0.0, 0.0, 0.0, 4.0);
+        assert!(big.contains_sphere(&Sphere::new(0.0, 1.0, 1.0, 1.0)));
+        assert!(!big.contains_sphere(&Sphere::new(0.0, 0.0, 3.5, 1.0)));
+        assert!(big.is_tangent_to(&Sphere::new(0.0, 0.0, 5.0, 1.0), 1e-9));
+        assert!(big.is_tangent_to(&Sphere::new(0.0, 0.0, 3.0, 1.0), 1e-9));
+        assert!(!big.is_tangent_to(&Sphere::new(0.0, 0.0, 4.0, 1.0), 1e-9));
+    }
+
+    #[test]
+    fn rect_cells() {
+        let cells: Vec<_> = Rect::new(1, -1, 2, 3).cells().collect();
+        assert_eq!(cells, vec![
+            Vector2::new_comp(1, -1),
+            Vector2::new_comp(2, -1),
+            Vector2::new_comp(1, 0),
+            Vector2::new_comp(2, 0),
+            Vector2::new_comp(1, 1),
+            Vector2::new_comp(2, 1),
+        ]);
+
+        assert_eq!(Rect::new(0, 0, 0, 5).cells().count(), 0);
+        assert_eq!(Rect::new(0, 0, -2, 2).cells().count(), 0);
+
+        let cells: Vec<_> = Rect::new(i32::MAX - 1, i32::MAX, 2, 1).cells().collect();
+        assert_eq!(cells, vec![Vector2::new_comp(i32::MAX - 1, i32::MAX), Vector2::new_comp(i32::MAX, i32::MAX)]);
+        assert_eq!(Rect::new(i32::MAX - 1, i32::MAX - 2, 10, 10).cells().count(), 6);
+        assert_eq!(Rect::new(i32::MIN, 0, 2, 1).cells().last(), Some(Vector2::new_comp(i32::MIN + 1, 0)));
+    }
+
+    #[test]
+    fn line_distance_to_segment() {
+        let a = Line2D::new(0.0, 0.0, 4.0, 4.0);
+        assert_eq!(a.distance_to_segment(&Line2D::new(0.0, 4.0, 4.0, 0.0)), 0.0);
+
+        let b = Line2D::new(0.0, 0.0, 4.0, 0.0);
+        assert_eq!(b.distance_to_segment(&Line2D::new(1.0, 3.0, 6.0, 3.0)), 3.0);
+        assert_eq!(b.distance_to_segment(&Line2D::new(2.0, 0.0, 6.0, 0.0)), 0.0);
+        assert_eq!(b.distance_to_segment(&Line2D::new(7.0, 4.0, 9.0, 4.0)), 5.0);
+
+        let c = Line3D::new(0.0, 0.0, 0.0, 2.0, 0.0, 0.0);
+        assert_eq!(c.distance_to_segment(&Line3D::new(1.0, -1.0, 2.0, 1.0, 1.0, 2.0)), 2.0);
+
+        // the infinite lines meet x = 4, but the segments are clamped to the end of `c`
+        let skew = Line3D::new(4.0, -1.0, 1.0, 4.0, 1.0, 1.0);
+        assert_eq!(c.distance_to_segment(&skew), 5.0f64.sqrt());
+    }
+
+    #[test]
+    fn line2d_normal() {
+        assert_eq!(Line2D::new(0.0, 0.0, 5.0, 0.0).normal(), Vector2::new_comp(0.0, 1.0));
+        assert_eq!(Line2D::new(5.0, 0.0, 0.0, 0.0).normal(), Vector2::new_comp(0.0, -1.0));
+        assert_eq!(Line2D::new(1.0, 1.0, 1.0, 4.0).normal(), Vector2::new_comp(-1.0, 0.0));
+
+        let normal = Line2D::new(0.0, 0.0, 3.0, 4.0).normal();
+        assert!(Vector2::distance(normal, Vector2::new_comp(-0.8, 0.6)) < 1e-12);
+    }
+
+    #[test]
+    fn rect_to_bounds_paths_agree() {
+        let rect = Rect::new(-2.0, 1.0, 10.0, 3.0);
+        let direct = Bounds2D::from(rect);
+        let via_area = Bounds2D::from(Area2D::from(rect));
+
+        assert_eq!(direct, via_area);
+        assert_eq!(direct, Bounds2D::new(3.0, 2.5, 5.0, 1.5));
+    }
+
+    #[test]
+    fn overlap_matrix_3d() {
+        let cube = Cube::new(0.0, 0.0, 0.0, 2.0, 2.0, 2.0);
+
+        assert!(Area3D::new(1.0, 1.0, 1.0, 3.0, 3.0, 3.0).overlaps_cube(&cube));
+        assert!(!Area3D::new(3.0, 0.0, 0.0, 4.0, 1.0, 1.0).overlaps_cube(&cube));
+
+        assert!(Bounds3D::new(2.5, 1.0, 1.0, 1.0, 0.5, 0.5).overlaps_cube(&cube));
+        assert!(!Bounds3D::new(4.0, 1.0, 1.0, 1.0, 0.5, 0.5).overlaps_cube(&cube));
+
+        assert!(Sphere::new(3.0, 1.0, 1.0, 1.5).overlaps_cube(&cube));
+        assert!(!Sphere::new(3.0, 3.0, 3.0, 1.5).overlaps_cube(&cube));
+
+        let area = Area3D::new(0.0, 0.0, 0.0, 2.0, 2.0, 2.0);
+        assert!(Sphere::new(1.0, 1.0, 2.5, 1.0).overlaps_area(&area));
+        assert!(!Sphere::new(1.0, 1.0, 4.0, 1.0).overlaps_area(&area));
+
+        let bounds = Bounds3D::new(0.0, 0.0, 0.0, 1.0, 1.0, 1.0);
+        assert!(Sphere::new(0.0, 1.5, 0.0, 1.0).overlaps_bounds(&bounds));
+        assert!(!Sphere::new(2.0, 2.0, 2.0, 1.0).overlaps_bounds(&bounds));
+    }
+
+    #[test]
+    fn shape_array_round_trip() {
+        let rect = Rect::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(rect.to_array(), [1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(Rect::from_array(rect.to_array()), rect);
+
+        let circle = Circle::new(-1.0, 0.5, 2.0);
+        assert_eq!(circle.to_array(), [-1.0, 0.5, 2.0]);
+        assert_eq!(Circle::from_array(circle.to_array()), circle);
+
+        let bounds = Bounds2D::new(0.0, 1.0, 2.0, 3.0);
+        assert_eq!(Bounds2D::from_array(bounds.to_array()), bounds);
+
+        let line = Line2D::new(5.0, 6.0, 7.0, 8.0);
+        assert_eq!(line.to_array(), [5.0, 6.0, 7.0, 8.0]);
+        assert_eq!(Line2D::from_array(line.to_array()), line);
+    }
+
+    #[test]
+    fn rect_and_bounds_scaled() {
+        let rect = Rect::new(1.0, 1.0, 2.0, 4.0);
+
+        let zoomed = rect.scaled_about(2.0, rect.get_center());
+        assert_eq!(zoomed.get_size(), Vector2::new_comp(4.0, 8.0));
+        assert_eq!(zoomed.get_center(), rect.get_center());
+
+        let from_corner = rect.scaled_about(2.0, rect.get_position());
+        assert_eq!(from_corner, Rect::new(1.0, 1.0, 4.0, 8.0));
+
+        let squashed = rect.scaled_about_vector(Vector2::new_comp(0.5, 1.0), Vector2::new_comp(0.0, 0.0));
+        assert_eq!(squashed, Rect::new(0.5, 1.0, 1.0, 4.0));
+
+        let bounds = Bounds2D::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(bounds.scaled(2.0), Bounds2D::new(1.0, 2.0, 6.0, 8.0));
+        assert_eq!(bounds.scaled_vector(Vector2::new_comp(1.0, 0.5)), Bounds2D::new(1.0, 2.0, 3.0, 2.0));
+    }
+
+    #[test]
+    fn circle_and_sphere_surface_points() {
+        let circle = Circle::new(1.0, -1.0, 2.0);
+        assert_eq!(circle.point_on_boundary(0.0), Vector2::new_comp(3.0, -1.0));
+        assert!(Vector2::distance(circle.point_on_boundary(f64::half_pi()), Vector2::new_comp(1.0, 1.0)) < 1e-12);
+
+        let sphere = Sphere::new(0.0, 1.0, 2.0, 3.0);
+        assert_eq!(sphere.point_on_surface(0.0, 0.0), Vector3::new_comp(0.0, 1.0, 5.0));
+        assert!(Vector3::distance(sphere.point_on_surface(f64::half_pi(), f64::half_pi()), Vector3::new_comp(0.0, 4.0, 2.0)) < 1e-12);
+
+        for angle in [0.3, 1.7, 2.9, 4.4, 6.0] {
+            assert!(f64::abs(Vector2::distance(circle.point_on_boundary(angle), circle.center) - circle.radius) < 1e-12);
+            assert!(f64::abs(Vector3::distance(sphere.point_on_surface(angle, angle * 2.0), sphere.center) - sphere.radius) < 1e-12);
+        }
+    }
+
+    #[test]
+    fn shape_distances() {
+        let a = Circle::new(0.0, 0.0, 1.0);
+        assert_eq!(a.distance_to(&Circle::new(5.0, 0.0, 2.0)), 2.0);
+        assert_eq!(a.distance_to(&Circle::new(2.0, 0.0, 2.0)), 0.0);
+        assert_eq!(a.distance_to(&a), 0.0);
+
+        let rect = Rect::new(2.0, -1.0, 2.0, 2.0);
+        assert_eq!(rect.distance_to(Vector2::new_comp(0.0, 0.0)), 2.0);
+        assert_eq!(rect.distance_to(Vector2::new_comp(3.0, 0.0)), 0.0);
+        assert_eq!(a.distance_to_rect(&rect), 1.0);
+        assert_eq!(Circle::new(0.0, 0.0, 3.0).distance_to_rect(&rect), 0.0);
+
+        let sphere = Sphere::new(0.0, 0.0, 0.0, 1.0);
+        assert_eq!(sphere.distance_to(&Sphere::new(0.0, 4.0, 0.0, 1.0)), 2.0);
+        assert_eq!(sphere.distance_to(&Sphere::new(0.0, 1.0, 0.0, 1.0)), 0.0);
+
+        let cube = Cube::new(3.0, 0.0, 0.0, 1.0, 1.0, 1.0);
+        assert_eq!(cube.distance_to(Vector3::new_comp(3.5, 0.5, -2.0)), 2.0);
+        assert_eq!(sphere.distance_to_cube(&Cube::new(3.0, -1.0, -1.0, 1.0, 2.0, 2.0)), 2.0);
+    }
+
+    #[test]
+    fn shape_array_conversions() {
+        let cube = Cube::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(cube, Cube::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0));
+        assert_eq!(<[f64; 6]>::from(cube), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let rect: Rect<f64> = [0.0, 1.0, 2.0, 3.0].into();
+        assert_eq!(rect, Rect::new(0.0, 1.0, 2.0, 3.0));
+        let array: [f64; 4] = rect.into();
+        assert_eq!(array, [0.0, 1.0, 2.0, 3.0]);
+
+        assert_eq!(Circle::from([1.0, 2.0, 3.0]), Circle::new(1.0, 2.0, 3.0));
+        assert_eq!(<[f64; 4]>::from(Sphere::new(1.0, 2.0, 3.0, 4.0)), [1.0, 2.0, 3.0, 4.0]);
+
+        let area = Area3D::new(0.0, 0.0, 0.0, 1.0, 2.0, 3.0);
+        assert_eq!(Area3D::from(<[f64; 6]>::from(area)), area);
+        let bounds = Bounds3D::new(0.0, 0.0, 0.0, 1.0, 2.0, 3.0);
+        assert_eq!(Bounds3D::from(<[f64; 6]>::from(bounds)), bounds);
+        let line = Line3D::new(0.0, 1.0, 2.0, 3.0, 4.0, 5.0);
+        assert_eq!(Line3D::from(<[f64; 6]>::from(line)), line);
+    }
+
+    #[test]
+    fn line2d_intersects() {
+        let a = Line2D::new(0.0, 0.0, 4.0, 4.0);
+        assert_eq!(a.intersects(&Line2D::new(0.0, 4.0, 4.0, 0.0)), Some(Vector2::new_comp(2.0, 2.0)));
+        assert_eq!(a.intersects(&Line2D::new(4.0, 0.0, 0.0, 4.0)), Some(Vector2::new_comp(2.0, 2.0)));
+
+        // touching at an endpoint counts
+        assert_eq!(a.intersects(&Line2D::new(4.0, 4.0, 6.0, 0.0)), Some(Vector2::new_comp(4.0, 4.0)));
+        assert_eq!(a.intersects(&Line2D::new(1.0, 1.0, 3.0, -1.0)), Some(Vector2::new_comp(1.0, 1.0)));
+
+        // the infinite lines cross, but outside one of the segments
+        assert_eq!(a.intersects(&Line2D::new(5.0, 0.0, 6.0, -1.0)), None);
+        assert_eq!(a.intersects(&Line2D::new(0.0, 10.0, 10.0, 0.0)), None);
+
+        // parallel and collinear overlapping segments have no single intersection point
+        assert_eq!(a.intersects(&Line2D::new(0.0, 1.0, 4.0, 5.0)), None);
+        assert_eq!(a.intersects(&Line2D::new(2.0, 2.0, 6.0, 6.0)), None);
+
+        let vertical = Line2D::new(1.0, -1.0, 1.0, 1.0);
+        assert_eq!(vertical.intersects(&Line2D::new(-1.0, 0.5, 3.0, 0.5)), Some(Vector2::new_comp(1.0, 0.5)));
+    }
+
+    #[test]
+    fn bounds_transformed() {
+        let quarter_pi = f64::quarter_pi();
+        let half_diagonal = 0.5 * 2.0f64.sqrt();
+
+        let bounds = Bounds2D::new(0.0, 0.0, 0.5, 0.5).rotated(quarter_pi);
+        assert!(bounds.center.approx_eq(Vector2::new_comp(0.0, 0.0), 1e-12));
+        assert!(bounds.extents.approx_eq(Vector2::new_comp(half_diagonal, half_diagonal), 1e-12));
+
+        let scaled = Bounds2D::new(1.0, 0.0, 1.0, 2.0).transformed(&Matrix2::from_scale(Vector2::new_comp(2.0, -1.0)));
+        assert_eq!(scaled, Bounds2D::new(2.0, 0.0, 2.0, 2.0));
+
+        let bounds = Bounds3D::new(0.0, 0.0, 0.0, 0.5, 0.5, 0.5).transformed(&Matrix3::from_rotation(quarter_pi));
+        assert!(bounds.center.approx_eq(Vector3::new_comp(0.0, 0.0, 0.0), 1e-12));
+        assert!(bounds.extents.approx_eq(Vector3::new_comp(half_diagonal, half_diagonal, 0.5), 1e-12));
+
+        let moved = Bounds3D::new(2.0, 0.0, 0.0, 1.0, 1.0, 1.0).transformed(&Matrix3::from_rotation(f64::half_pi()));
+        assert!(moved.center.approx_eq(Vector3::new_comp(0.0, 2.0, 0.0), 1e-12));
+        assert!(moved.extents.approx_eq(Vector3::new_comp(1.0, 1.0, 1.0), 1e-12));
+
+        let x_axis = Vector3::new_comp(1.0, 0.0, 0.0);
+        let bounds = Bounds3D::new(0.0, 0.0, 0.0, 0.5, 0.5, 0.5).transformed(&Matrix3::from_axis_angle(x_axis, quarter_pi));
+        assert!(bounds.center.approx_eq(Vector3::new_comp(0.0, 0.0, 0.0), 1e-12));
+        assert!(bounds.extents.approx_eq(Vector3::new_comp(0.5, half_diagonal, half_diagonal), 1e-12));
+
+        let moved = Bounds3D::new(0.0, 2.0, 0.0, 1.0, 2.0, 3.0).transformed(&Matrix3::from_axis_angle(x_axis, f64::half_pi()));
+        assert!(moved.center.approx_eq(Vector3::new_comp(0.0, 0.0, 2.0), 1e-12));
+        assert!(moved.extents.approx_eq(Vector3::new_comp(1.0, 3.0, 2.0), 1e-12));
+    }
+
+    #[test]
+    fn rect_and_cube_subdivide() {
+        let rect = Rect::new(0.0, 0.0, 1.0, 1.0);
+        let quadrants = rect.subdivide();
+        let corners = rect.corners();
+
+        for (i, quadrant) in quadrants.iter().enumerate() {
+            assert_eq!(quadrant.get_size(), Vector2::new_comp(0.5, 0.5));
+            assert_eq!(quadrant.corners()[i], corners[i]);
+
+            for (j, other) in quadrants.iter().enumerate() {
+                if i != j {
+                    assert!(!quadrant.contains(other.get_center()));
+                }
+            }
+        }
+
+        let area: f64 = quadrants.iter().map(|quadrant| quadrant.width * quadrant.height).sum();
+        assert_eq!(area, 1.0);
+        assert_eq!(quadrants[2], Rect::new(0.5, 0.5, 0.5, 0.5));
+        assert_eq!(quadrants.iter().fold(quadrants[0], |union, quadrant| union.union(quadrant)), rect);
+
+        let cube = Cube::new(-1.0, -1.0, -1.0, 2.0, 2.0, 2.0);
+        let octants = cube.subdivide();
+        let corners = cube.corners();
+
+        for (i, octant) in octants.iter().enumerate() {
+            assert_eq!(octant.get_size(), Vector3::new_comp(1.0, 1.0, 1.0));
+            assert_eq!(octant.corners()[i], corners[i]);
+        }
+
+        assert_eq!(octants[6], Cube::new(0.0, 0.0, 0.0, 1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn hypersphere_overlaps_box() {
+        let area = Area4D::new(0.0, 0.0, 0.0, 0.0, 2.0, 2.0, 2.0, 2.0);
+        let bounds = Bounds4D::new(1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0);
+
+        let straddling = HyperSphere::new(1.0, 1.0, 1.0, 2.5, 1.0);
+        assert!(straddling.overlaps_area(&area));
+        assert!(straddling.overlaps_bounds(&bounds));
+        assert!(area.overlaps_hypersphere(&straddling));
+        assert!(bounds.overlaps_hypersphere(&straddling));
+
+        let outside_w = HyperSphere::new(1.0, 1.0, 1.0, 3.5, 1.0);
+        assert!(!outside_w.overlaps_area(&area));
+        assert!(!bounds.overlaps_hypersphere(&outside_w));
+
+        // each axis is within reach on its own, but the corner is further than the radius
+        let near_corner = HyperSphere::new(2.75, 2.75, 2.75, 2.75, 1.0);
+        assert!(!near_corner.overlaps_area(&area));
+        assert!(!near_corner.overlaps_bounds(&bounds));
+
+        let touching = HyperSphere::new(1.0, -1.0, 1.0, 1.0, 1.0);
+        assert!(touching.overlaps_area(&area));
+        assert!(bounds.overlaps_hypersphere(&touching));
+
+        let inside = HyperSphere::new(1.0, 1.0, 1.0, 1.0, 0.25);
+        assert!(inside.overlaps_area(&area));
+        assert!(inside.overlaps_bounds(&bounds));
+    }
+
+    #[test]
+    fn from_min_max_and_center_size() {
+        let bounds = Bounds3D::from_min_max(Vector3::new_comp(-1.0, 2.0, 0.0), Vector3::new_comp(3.0, 4.0, 10.0));
+        assert_eq!(bounds, Bounds3D::new(1.0, 3.0, 5.0, 2.0, 1.0, 5.0));
+        assert_eq!((bounds.get_x_min(), bounds.get_x_max()), (-1.0, 3.0));
+        assert_eq!((bounds.get_y_min(), bounds.get_y_max()), (2.0, 4.0));
+        assert_eq!((bounds.get_z_min(), bounds.get_z_max()), (0.0, 10.0));
+        assert_eq!(Bounds3D::from_center_size(Vector3::new_comp(1.0, 3.0, 5.0), Vector3::new_comp(4.0, 2.0, 10.0)), bounds);
+
+        let bounds = Bounds2D::from_min_max(Vector2::new_comp(-1.0, 2.0), Vector2::new_comp(3.0, 4.0));
+        assert_eq!((bounds.get_x_min(), bounds.get_x_max()), (-1.0, 3.0));
+        assert_eq!((bounds.get_y_min(), bounds.get_y_max()), (2.0, 4.0));
+        assert_eq!(Bounds2D::from_center_size(Vector2::new_comp(1.0, 3.0), Vector2::new_comp(4.0, 2.0)), bounds);
+
+        let area = Area2D::from_center_size(Vector2::new_comp(1.0, 3.0), Vector2::new_comp(4.0, 2.0));
+        assert_eq!(area, Area2D::from_min_max(Vector2::new_comp(-1.0, 2.0), Vector2::new_comp(3.0, 4.0)));
+        let area = Area3D::from_center_size(Vector3::new_comp(1.0, 3.0, 5.0), Vector3::new_comp(4.0, 2.0, 10.0));
+        assert_eq!(area, Area3D::new(-1.0, 2.0, 0.0, 3.0, 4.0, 10.0));
+
+        let rect = Rect::from_center_size(Vector2::new_comp(1.0, 3.0), Vector2::new_comp(4.0, 2.0));
+        assert_eq!(rect, Rect::new(-1.0, 2.0, 4.0, 2.0));
+        assert_eq!(rect.get_center(), Vector2::new_comp(1.0, 3.0));
+        let cube = Cube::from_center_size(Vector3::new_comp(1.0, 3.0, 5.0), Vector3::new_comp(4.0, 2.0, 10.0));
+        assert_eq!(cube, Cube::new(-1.0, 2.0, 0.0, 4.0, 2.0, 10.0));
+    }
+
+    #[test]
+    fn line2d_intersect_infinite() {
+        let a = Line2D::new(0.0, 0.0, 1.0, 1.0);
+        let b = Line2D::new(4.0, 0.0, 3.0, 1.0);
+        assert_eq!(a.intersects(&b), None);
+        assert_eq!(a.intersect_infinite(&b), Some(Vector2::new_comp(2.0, 2.0)));
+        assert_eq!(b.intersect_infinite(&a), Some(Vector2::new_comp(2.0, 2.0)));
+
+        let c = Line2D::new(0.0, 4.0, 4.0, 0.0);
+        assert_eq!(a.intersects(&c), None);
+        assert_eq!(a.intersect_infinite(&c), Some(Vector2::new_comp(2.0, 2.0)));
+
+        let crossing = Line2D::new(0.0, 1.0, 1.0, 0.0);
+        assert_eq!(a.intersect_infinite(&crossing), a.intersects(&crossing));
+
+        let parallel = Line2D::new(0.0, 1.0, 1.0, 2.0);
+        assert_eq!(a.intersect_infinite(&parallel), None);
+        let collinear = Line2D::new(2.0, 2.0, 3.0, 3.0);
+        assert_eq!(a.intersect_infinite(&collinear), None);
+    }
+
+    #[test]
+    fn line2d_scaled_about_center() {
+        let line = Line2D::new(0.0, 0.0, 4.0, 0.0);
+        let scaled = line.scaled_about_center(2.0);
+        assert_eq!(scaled.get_center(), line.get_center());
+        assert_eq!(scaled.get_length(), 8.0);
+        assert_eq!(scaled, Line2D::new(-2.0, 0.0, 6.0, 0.0));
+
+        let shrunk = line.scaled_about_center(0.5);
+        assert_eq!(shrunk, Line2D::new(1.0, 0.0, 3.0, 0.0));
+    }
 }
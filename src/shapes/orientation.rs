@@ -0,0 +1,49 @@
+use num_traits::real::Real;
+
+use crate::vectors::Vector2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    Clockwise,
+    CounterClockwise,
+    Collinear,
+}
+
+/// Winding of the triangle `a`, `b`, `c`, from the sign of the cross product of `b - a` and `c - a`.
+/// Cross products within a relative epsilon of zero count as collinear.
+#[inline]
+pub fn orientation<T>(a: Vector2<T>, b: Vector2<T>, c: Vector2<T>) -> Orientation
+where T: Real {
+    let ab = b - a;
+    let ac = c - a;
+    let cross = Vector2::perp_dot(ab, ac);
+    let tolerance = T::epsilon() * ab.magnitude() * ac.magnitude();
+
+    if cross.abs() <= tolerance {
+        Orientation::Collinear
+    } else if cross > T::zero() {
+        Orientation::CounterClockwise
+    } else {
+        Orientation::Clockwise
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orientation_outcomes() {
+        let a = Vector2::new_comp(0.0, 0.0);
+        let b = Vector2::new_comp(1.0, 0.0);
+
+        assert_eq!(orientation(a, b, Vector2::new_comp(0.0, 1.0)), Orientation::CounterClockwise);
+        assert_eq!(orientation(a, b, Vector2::new_comp(0.0, -1.0)), Orientation::Clockwise);
+        assert_eq!(orientation(a, b, Vector2::new_comp(5.0, 0.0)), Orientation::Collinear);
+        assert_eq!(orientation(a, b, a), Orientation::Collinear);
+
+        let a = Vector2::new_comp(0.1, 0.1);
+        let b = Vector2::new_comp(0.2, 0.2);
+        assert_eq!(orientation(a, b, Vector2::new_comp(0.3, 0.3)), Orientation::Collinear);
+    }
+}
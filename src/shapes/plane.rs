@@ -0,0 +1,109 @@
+use std::ops::DivAssign;
+
+use num_traits::real::Real;
+
+use crate::vectors::Vector3;
+
+use super::Ray3D;
+
+/// The set of points `p` where `dot(normal, p) == distance`. `normal` is expected to be unit length.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Plane<T> {
+    pub normal: Vector3<T>,
+    pub distance: T,
+}
+
+impl<T> Plane<T> {
+    #[inline]
+    pub fn new(normal: Vector3<T>, distance: T) -> Self {
+        Self { normal, distance }
+    }
+
+    #[inline]
+    pub fn from_point_normal(point: Vector3<T>, normal: Vector3<T>) -> Self
+    where T: Real + DivAssign {
+        let normal = normal.normalized();
+        Self::new(normal, Vector3::dot(normal, point))
+    }
+
+    /// The normal follows the right-hand rule for `a`, `b`, `c`.
+    #[inline]
+    pub fn from_three_points(a: Vector3<T>, b: Vector3<T>, c: Vector3<T>) -> Self
+    where T: Real + DivAssign {
+        Self::from_point_normal(a, Vector3::cross(b - a, c - a))
+    }
+
+    #[inline]
+    pub fn signed_distance(&self, point: Vector3<T>) -> T
+    where T: Real {
+        Vector3::dot(self.normal, point) - self.distance
+    }
+
+    #[inline]
+    pub fn project_point(&self, point: Vector3<T>) -> Vector3<T>
+    where T: Real {
+        point - self.normal * self.signed_distance(point)
+    }
+
+    #[inline]
+    pub fn raycast(&self, ray: &Ray3D<T>) -> Option<T>
+    where T: Real {
+        let denominator = Vector3::dot(self.normal, ray.direction);
+
+        if denominator == T::zero() {
+            return None;
+        }
+
+        let t = -self.signed_distance(ray.origin) / denominator;
+
+        if t < T::zero() {
+            None
+        } else {
+            Some(t)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plane_constructors() {
+        let plane = Plane::from_point_normal(Vector3::new_comp(1.0, 2.0, 3.0), Vector3::new_comp(0.0, 0.0, 5.0));
+        assert_eq!(plane, Plane::new(Vector3::new_comp(0.0, 0.0, 1.0), 3.0));
+
+        let plane = Plane::from_three_points(
+            Vector3::new_comp(0.0, 0.0, 0.0),
+            Vector3::new_comp(2.0, 0.0, 0.0),
+            Vector3::new_comp(0.0, 3.0, 0.0));
+        assert_eq!(plane, Plane::new(Vector3::new_comp(0.0, 0.0, 1.0), 0.0));
+    }
+
+    #[test]
+    fn plane_signed_distance_and_projection() {
+        let plane = Plane::from_point_normal(Vector3::new_comp(0.0, 0.0, 0.0), Vector3::new_comp(0.0, 0.0, 1.0));
+
+        assert_eq!(plane.signed_distance(Vector3::new_comp(4.0, -2.0, 3.0)), 3.0);
+        assert_eq!(plane.signed_distance(Vector3::new_comp(4.0, -2.0, -1.5)), -1.5);
+        assert_eq!(plane.project_point(Vector3::new_comp(4.0, -2.0, 3.0)), Vector3::new_comp(4.0, -2.0, 0.0));
+        assert_eq!(plane.project_point(Vector3::new_comp(4.0, -2.0, -1.5)), Vector3::new_comp(4.0, -2.0, 0.0));
+    }
+
+    #[test]
+    fn plane_raycast() {
+        let plane = Plane::new(Vector3::new_comp(0.0, 0.0, 1.0), 0.0);
+
+        let ray = Ray3D::new(Vector3::new_comp(1.0, 1.0, 5.0), Vector3::new_comp(0.0, 0.0, -1.0));
+        assert_eq!(plane.raycast(&ray), Some(5.0));
+
+        let ray = Ray3D::new(Vector3::new_comp(1.0, 1.0, -2.0), Vector3::new_comp(0.0, 0.0, 2.0));
+        assert_eq!(plane.raycast(&ray), Some(1.0));
+
+        let ray = Ray3D::new(Vector3::new_comp(1.0, 1.0, 5.0), Vector3::new_comp(0.0, 0.0, 1.0));
+        assert_eq!(plane.raycast(&ray), None);
+
+        let ray = Ray3D::new(Vector3::new_comp(1.0, 1.0, 5.0), Vector3::new_comp(1.0, 0.0, 0.0));
+        assert_eq!(plane.raycast(&ray), None);
+    }
+}
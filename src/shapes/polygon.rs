@@ -0,0 +1,135 @@
+use num_traits::real::Real;
+
+use crate::vectors::Vector2;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Polygon2D<T> {
+    pub vertices: Vec<Vector2<T>>,
+}
+
+impl<T> Polygon2D<T> {
+    #[inline]
+    pub fn new(vertices: Vec<Vector2<T>>) -> Self {
+        Self { vertices }
+    }
+
+    #[inline]
+    fn edges(&self) -> impl Iterator<Item = (Vector2<T>, Vector2<T>)> + '_
+    where T: Copy {
+        self.vertices.iter().copied().zip(self.vertices.iter().copied().cycle().skip(1))
+    }
+
+    /// Signed shoelace area: positive for counter-clockwise winding, negative for clockwise.
+    /// Self-intersecting polygons sum the signed areas of their loops.
+    #[inline]
+    pub fn area(&self) -> T
+    where T: Real {
+        let twice_area = self.edges()
+            .fold(T::zero(), |sum, (a, b)| sum + a.x * b.y - b.x * a.y);
+
+        twice_area / (T::one() + T::one())
+    }
+
+    #[inline]
+    pub fn perimeter(&self) -> T
+    where T: Real {
+        self.edges()
+            .fold(T::zero(), |sum, (a, b)| sum + Vector2::distance(a, b))
+    }
+
+    #[inline]
+    pub fn centroid(&self) -> Vector2<T>
+    where T: Real {
+        let area = self.area();
+
+        if area == T::zero() {
+            let count = T::from(self.vertices.len()).unwrap();
+            return self.vertices.iter().fold(Vector2::new_comp(T::zero(), T::zero()), |sum, v| sum + *v) / count;
+        }
+
+        let sum = self.edges().fold(Vector2::new_comp(T::zero(), T::zero()), |sum, (a, b)| {
+            let cross = a.x * b.y - b.x * a.y;
+            sum + (a + b) * cross
+        });
+
+        let six = T::from(6).unwrap();
+        sum / (six * area)
+    }
+
+    #[inline]
+    pub fn contains(&self, point: Vector2<T>) -> bool
+    where T: Real {
+        let mut inside = false;
+
+        for (a, b) in self.edges() {
+            if (a.y > point.y) != (b.y > point.y) {
+                let x = a.x + (point.y - a.y) * (b.x - a.x) / (b.y - a.y);
+
+                if point.x < x {
+                    inside = !inside;
+                }
+            }
+        }
+
+        inside
+    }
+}
+
+impl<T> From<Vec<Vector2<T>>> for Polygon2D<T> {
+    #[inline]
+    fn from(vertices: Vec<Vector2<T>>) -> Self {
+        Self::new(vertices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn l_shape() -> Polygon2D<f64> {
+        Polygon2D::new(vec![
+            Vector2::new_comp(0.0, 0.0),
+            Vector2::new_comp(2.0, 0.0),
+            Vector2::new_comp(2.0, 1.0),
+            Vector2::new_comp(1.0, 1.0),
+            Vector2::new_comp(1.0, 2.0),
+            Vector2::new_comp(0.0, 2.0),
+        ])
+    }
+
+    #[test]
+    fn polygon_area_and_perimeter() {
+        let polygon = l_shape();
+        assert_eq!(polygon.area(), 3.0);
+        assert_eq!(polygon.perimeter(), 8.0);
+
+        let mut clockwise = polygon.clone();
+        clockwise.vertices.reverse();
+        assert_eq!(clockwise.area(), -3.0);
+
+        let bowtie = Polygon2D::new(vec![
+            Vector2::new_comp(0.0, 0.0),
+            Vector2::new_comp(2.0, 2.0),
+            Vector2::new_comp(2.0, 0.0),
+            Vector2::new_comp(0.0, 2.0),
+        ]);
+        assert_eq!(bowtie.area(), 0.0);
+    }
+
+    #[test]
+    fn polygon_centroid() {
+        let centroid = l_shape().centroid();
+        assert!(Vector2::distance(centroid, Vector2::new_comp(5.0 / 6.0, 5.0 / 6.0)) < 1e-12);
+    }
+
+    #[test]
+    fn polygon_contains() {
+        let polygon = l_shape();
+        assert!(polygon.contains(Vector2::new_comp(0.5, 0.5)));
+        assert!(polygon.contains(Vector2::new_comp(1.5, 0.5)));
+        assert!(polygon.contains(Vector2::new_comp(0.5, 1.5)));
+        assert!(!polygon.contains(Vector2::new_comp(1.5, 1.5)));
+        assert!(!polygon.contains(Vector2::new_comp(-0.5, 0.5)));
+        assert!(!polygon.contains(Vector2::new_comp(0.5, 2.5)));
+    }
+}
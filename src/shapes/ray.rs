@@ -0,0 +1,234 @@
+use num_traits::real::Real;
+
+use crate::vectors::{Vector2, Vector3};
+
+use super::{AabbN, Rect, Circle, Cube, Sphere};
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Ray2D<T> {
+    pub origin: Vector2<T>,
+    pub direction: Vector2<T>,
+}
+
+impl<T> Ray2D<T> {
+    #[inline]
+    pub fn new(origin: Vector2<T>, direction: Vector2<T>) -> Self {
+        Self { origin, direction }
+    }
+
+    #[inline]
+    pub fn point_at(&self, t: T) -> Vector2<T>
+    where T: Real {
+        self.origin + self.direction * t
+    }
+
+    /// Hit distances are in multiples of `direction`, so they are only world-space
+    /// distances when `direction` is normalized. A ray starting inside the shape hits at `0`.
+    #[inline]
+    pub fn intersect_rect(&self, rect: &Rect<T>) -> Option<T>
+    where T: Real {
+        let aabb = AabbN::from(*rect);
+        let origin: [T; 2] = self.origin.into();
+        let direction: [T; 2] = self.direction.into();
+        slab(&origin, &direction, &aabb.min, &aabb.max)
+    }
+
+    #[inline]
+    pub fn intersect_circle(&self, circle: &Circle<T>) -> Option<T>
+    where T: Real {
+        let offset = self.origin - circle.center;
+        let a = Vector2::dot(self.direction, self.direction);
+        let b = Vector2::dot(offset, self.direction);
+        let c = Vector2::dot(offset, offset) - circle.radius * circle.radius;
+        quadratic_hit(a, b, c)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Ray3D<T> {
+    pub origin: Vector3<T>,
+    pub direction: Vector3<T>,
+}
+
+impl<T> Ray3D<T> {
+    #[inline]
+    pub fn new(origin: Vector3<T>, direction: Vector3<T>) -> Self {
+        Self { origin, direction }
+    }
+
+    #[inline]
+    pub fn point_at(&self, t: T) -> Vector3<T>
+    where T: Real {
+        self.origin + self.direction * t
+    }
+
+    /// Hit distances follow the same conventions as [`Ray2D::intersect_rect`].
+    #[inline]
+    pub fn intersect_cube(&self, cube: &Cube<T>) -> Option<T>
+    where T: Real {
+        let aabb = AabbN::from(*cube);
+        let origin: [T; 3] = self.origin.into();
+        let direction: [T; 3] = self.direction.into();
+        slab(&origin, &direction, &aabb.min, &aabb.max)
+    }
+
+    #[inline]
+    pub fn intersect_sphere(&self, sphere: &Sphere<T>) -> Option<T>
+    where T: Real {
+        let offset = self.origin - sphere.center;
+        let a = Vector3::dot(self.direction, self.direction);
+        let b = Vector3::dot(offset, self.direction);
+        let c = Vector3::dot(offset, offset) - sphere.radius * sphere.radius;
+        quadratic_hit(a, b, c)
+    }
+}
+
+fn slab<T>(origin: &[T], direction: &[T], min: &[T], max: &[T]) -> Option<T>
+where T: Real {
+    let mut t_min = T::min_value();
+    let mut t_max = T::max_value();
+
+    for i in 0..origin.len() {
+        if direction[i] == T::zero() {
+            if origin[i] < min[i] || origin[i] > max[i] {
+                return None;
+            }
+
+            continue;
+        }
+
+        let t1 = (min[i] - origin[i]) / direction[i];
+        let t2 = (max[i] - origin[i]) / direction[i];
+
+        t_min = t_min.max(t1.min(t2));
+        t_max = t_max.min(t1.max(t2));
+
+        if t_min > t_max {
+            return None;
+        }
+    }
+
+    if t_max < T::zero() {
+        None
+    } else {
+        Some(t_min.max(T::zero()))
+    }
+}
+
+/// Solves `a*t^2 + 2*b*t + c = 0` for the nearest non-negative `t`.
+fn quadratic_hit<T>(a: T, b: T, c: T) -> Option<T>
+where T: Real {
+    if a == T::zero() {
+        return None;
+    }
+
+    let discriminant = b * b - a * c;
+
+    if discriminant < T::zero() {
+        return None;
+    }
+
+    let root = discriminant.sqrt();
+    let near = (-b - root) / a;
+    let far = (-b + root) / a;
+
+    if far < T::zero() {
+        None
+    } else {
+        Some(near.max(T::zero()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ray2d_intersect_rect() {
+        let rect = Rect::new(2.0, -1.0, 2.0, 2.0);
+
+        let ray = Ray2D::new(Vector2::new_comp(0.0, 0.0), Vector2::new_comp(1.0, 0.0));
+        assert_eq!(ray.intersect_rect(&rect), Some(2.0));
+
+        let ray = Ray2D::new(Vector2::new_comp(0.0, 0.0), Vector2::new_comp(4.0, 0.0));
+        assert_eq!(ray.intersect_rect(&rect), Some(0.5));
+
+        let ray = Ray2D::new(Vector2::new_comp(0.0, 0.0), Vector2::new_comp(-1.0, 0.0));
+        assert_eq!(ray.intersect_rect(&rect), None);
+
+        let ray = Ray2D::new(Vector2::new_comp(0.0, 2.0), Vector2::new_comp(1.0, 0.0));
+        assert_eq!(ray.intersect_rect(&rect), None);
+
+        let ray = Ray2D::new(Vector2::new_comp(3.0, 0.0), Vector2::new_comp(0.0, 1.0));
+        assert_eq!(ray.intersect_rect(&rect), Some(0.0));
+
+        let ray = Ray2D::new(Vector2::new_comp(0.0, 1.0), Vector2::new_comp(1.0, 0.0));
+        assert_eq!(ray.intersect_rect(&rect), Some(2.0));
+    }
+
+    #[test]
+    fn ray2d_intersect_circle() {
+        let circle = Circle::new(5.0, 0.0, 1.0);
+
+        let ray = Ray2D::new(Vector2::new_comp(0.0, 0.0), Vector2::new_comp(1.0, 0.0));
+        assert_eq!(ray.intersect_circle(&circle), Some(4.0));
+        assert_eq!(ray.point_at(4.0), Vector2::new_comp(4.0, 0.0));
+
+        let ray = Ray2D::new(Vector2::new_comp(0.0, 0.0), Vector2::new_comp(2.0, 0.0));
+        assert_eq!(ray.intersect_circle(&circle), Some(2.0));
+
+        let ray = Ray2D::new(Vector2::new_comp(0.0, 0.0), Vector2::new_comp(0.0, 1.0));
+        assert_eq!(ray.intersect_circle(&circle), None);
+
+        let ray = Ray2D::new(Vector2::new_comp(0.0, 0.0), Vector2::new_comp(-1.0, 0.0));
+        assert_eq!(ray.intersect_circle(&circle), None);
+
+        let ray = Ray2D::new(Vector2::new_comp(5.0, 0.5), Vector2::new_comp(1.0, 0.0));
+        assert_eq!(ray.intersect_circle(&circle), Some(0.0));
+
+        let ray = Ray2D::new(Vector2::new_comp(0.0, 1.0), Vector2::new_comp(1.0, 0.0));
+        assert_eq!(ray.intersect_circle(&circle), Some(5.0));
+    }
+
+    #[test]
+    fn ray3d_intersect_cube() {
+        let cube = Cube::new(-1.0, -1.0, -1.0, 2.0, 2.0, 2.0);
+
+        let ray = Ray3D::new(Vector3::new_comp(0.0, 0.0, -5.0), Vector3::new_comp(0.0, 0.0, 1.0));
+        assert_eq!(ray.intersect_cube(&cube), Some(4.0));
+
+        let ray = Ray3D::new(Vector3::new_comp(-3.0, -3.0, -3.0), Vector3::new_comp(1.0, 1.0, 1.0));
+        assert_eq!(ray.intersect_cube(&cube), Some(2.0));
+
+        let ray = Ray3D::new(Vector3::new_comp(-3.0, -3.0, -3.0), Vector3::new_comp(-1.0, 1.0, 1.0));
+        assert_eq!(ray.intersect_cube(&cube), None);
+
+        let ray = Ray3D::new(Vector3::new_comp(0.0, 2.0, -5.0), Vector3::new_comp(0.0, 0.0, 1.0));
+        assert_eq!(ray.intersect_cube(&cube), None);
+
+        let ray = Ray3D::new(Vector3::new_comp(0.0, 0.0, 0.0), Vector3::new_comp(0.0, 1.0, 0.0));
+        assert_eq!(ray.intersect_cube(&cube), Some(0.0));
+    }
+
+    #[test]
+    fn ray3d_intersect_sphere() {
+        let sphere = Sphere::new(0.0, 0.0, 10.0, 2.0);
+
+        let ray = Ray3D::new(Vector3::new_comp(0.0, 0.0, 0.0), Vector3::new_comp(0.0, 0.0, 1.0));
+        assert_eq!(ray.intersect_sphere(&sphere), Some(8.0));
+
+        let ray = Ray3D::new(Vector3::new_comp(10.0, 0.0, 0.0), Vector3::new_comp(-1.0, 0.0, 1.0));
+        let t = ray.intersect_sphere(&sphere).unwrap();
+        assert!(f64::abs(Vector3::distance(ray.point_at(t), sphere.center) - 2.0) < 1e-12);
+        assert!(t > 0.0);
+
+        let ray = Ray3D::new(Vector3::new_comp(0.0, 2.0, 0.0), Vector3::new_comp(0.0, 0.0, 1.0));
+        assert_eq!(ray.intersect_sphere(&sphere), Some(10.0));
+
+        let ray = Ray3D::new(Vector3::new_comp(0.0, 3.0, 0.0), Vector3::new_comp(0.0, 0.0, 1.0));
+        assert_eq!(ray.intersect_sphere(&sphere), None);
+
+        let ray = Ray3D::new(Vector3::new_comp(0.0, 0.0, 9.0), Vector3::new_comp(0.0, 0.0, -1.0));
+        assert_eq!(ray.intersect_sphere(&sphere), Some(0.0));
+    }
+}
@@ -0,0 +1,180 @@
+use std::ops::DivAssign;
+
+use num_traits::real::Real;
+
+use crate::vectors::{Vector2, Vector3};
+
+/// Unsigned area of the triangle `a`, `b`, `c`. Collinear points give zero.
+#[inline]
+pub fn triangle_area<T>(a: Vector2<T>, b: Vector2<T>, c: Vector2<T>) -> T
+where T: Real {
+    Vector2::perp_dot(b - a, c - a).abs() / (T::one() + T::one())
+}
+
+/// Area of the triangle `a`, `b`, `c` in 3D space, half the magnitude of the cross product.
+#[inline]
+pub fn triangle_area3<T>(a: Vector3<T>, b: Vector3<T>, c: Vector3<T>) -> T
+where T: Real {
+    Vector3::cross(b - a, c - a).magnitude() / (T::one() + T::one())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Triangle3D<T> {
+    pub a: Vector3<T>,
+    pub b: Vector3<T>,
+    pub c: Vector3<T>,
+}
+
+impl<T> Triangle3D<T> {
+    #[inline]
+    pub fn new(a: Vector3<T>, b: Vector3<T>, c: Vector3<T>) -> Self {
+        Self { a, b, c }
+    }
+
+    /// Unit normal following the right-hand rule for `a`, `b`, `c`.
+    #[inline]
+    pub fn normal(&self) -> Vector3<T>
+    where T: Real + DivAssign {
+        Vector3::cross(self.b - self.a, self.c - self.a).normalized()
+    }
+
+    #[inline]
+    pub fn area(&self) -> T
+    where T: Real {
+        triangle_area3(self.a, self.b, self.c)
+    }
+
+    /// Weights `(u, v, w)` of `a`, `b` and `c` for the projection of `point` onto the triangle's
+    /// plane, so that it equals `a * u + b * v + c * w`. Degenerate triangles give NaN weights.
+    #[inline]
+    pub fn barycentric(&self, point: Vector3<T>) -> (T, T, T)
+    where T: Real {
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+        let ap = point - self.a;
+
+        let d00 = Vector3::dot(ab, ab);
+        let d01 = Vector3::dot(ab, ac);
+        let d11 = Vector3::dot(ac, ac);
+        let d20 = Vector3::dot(ap, ab);
+        let d21 = Vector3::dot(ap, ac);
+        let denominator = d00 * d11 - d01 * d01;
+
+        let v = (d11 * d20 - d01 * d21) / denominator;
+        let w = (d00 * d21 - d01 * d20) / denominator;
+        (T::one() - v - w, v, w)
+    }
+
+    // Voronoi region tests, see Ericson's Real-Time Collision Detection 5.1.5
+    #[inline]
+    pub fn closest_point(&self, point: Vector3<T>) -> Vector3<T>
+    where T: Real {
+        let zero = T::zero();
+        let (a, b, c) = (self.a, self.b, self.c);
+        let ab = b - a;
+        let ac = c - a;
+
+        let ap = point - a;
+        let d1 = Vector3::dot(ab, ap);
+        let d2 = Vector3::dot(ac, ap);
+
+        if d1 <= zero && d2 <= zero {
+            return a;
+        }
+
+        let bp = point - b;
+        let d3 = Vector3::dot(ab, bp);
+        let d4 = Vector3::dot(ac, bp);
+
+        if d3 >= zero && d4 <= d3 {
+            return b;
+        }
+
+        let vc = d1 * d4 - d3 * d2;
+
+        if vc <= zero && d1 >= zero && d3 <= zero {
+            return a + ab * (d1 / (d1 - d3));
+        }
+
+        let cp = point - c;
+        let d5 = Vector3::dot(ab, cp);
+        let d6 = Vector3::dot(ac, cp);
+
+        if d6 >= zero && d5 <= d6 {
+            return c;
+        }
+
+        let vb = d5 * d2 - d1 * d6;
+
+        if vb <= zero && d2 >= zero && d6 <= zero {
+            return a + ac * (d2 / (d2 - d6));
+        }
+
+        let va = d3 * d6 - d5 * d4;
+
+        if va <= zero && d4 - d3 >= zero && d5 - d6 >= zero {
+            return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+
+        let denominator = va + vb + vc;
+        a + ab * (vb / denominator) + ac * (vc / denominator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn triangle_areas() {
+        let a = Vector2::new_comp(0.0, 0.0);
+        let b = Vector2::new_comp(1.0, 0.0);
+        let c = Vector2::new_comp(0.0, 1.0);
+        assert_eq!(triangle_area(a, b, c), 0.5);
+        assert_eq!(triangle_area(a, c, b), 0.5);
+        assert_eq!(triangle_area(a, b, Vector2::new_comp(3.0, 0.0)), 0.0);
+
+        let a = Vector3::new_comp(0.0, 0.0, 1.0);
+        let b = Vector3::new_comp(0.0, 2.0, 1.0);
+        let c = Vector3::new_comp(0.0, 0.0, 4.0);
+        assert_eq!(triangle_area3(a, b, c), 3.0);
+        assert_eq!(triangle_area3(a, b, Vector3::new_comp(0.0, 4.0, 1.0)), 0.0);
+    }
+
+    fn triangle() -> Triangle3D<f64> {
+        Triangle3D::new(
+            Vector3::new_comp(0.0, 0.0, 0.0),
+            Vector3::new_comp(4.0, 0.0, 0.0),
+            Vector3::new_comp(0.0, 4.0, 0.0))
+    }
+
+    #[test]
+    fn triangle3d_normal_area_and_barycentric() {
+        let triangle = triangle();
+        assert_eq!(triangle.normal(), Vector3::new_comp(0.0, 0.0, 1.0));
+        assert_eq!(triangle.area(), 8.0);
+
+        assert_eq!(triangle.barycentric(triangle.a), (1.0, 0.0, 0.0));
+        assert_eq!(triangle.barycentric(triangle.c), (0.0, 0.0, 1.0));
+        assert_eq!(triangle.barycentric(Vector3::new_comp(1.0, 2.0, 5.0)), (0.25, 0.25, 0.5));
+    }
+
+    #[test]
+    fn triangle3d_closest_point() {
+        let triangle = triangle();
+
+        // vertex regions
+        assert_eq!(triangle.closest_point(Vector3::new_comp(-1.0, -1.0, 3.0)), triangle.a);
+        assert_eq!(triangle.closest_point(Vector3::new_comp(6.0, -1.0, 0.0)), triangle.b);
+        assert_eq!(triangle.closest_point(Vector3::new_comp(-1.0, 7.0, -2.0)), triangle.c);
+
+        // edge regions
+        assert_eq!(triangle.closest_point(Vector3::new_comp(2.0, -3.0, 1.0)), Vector3::new_comp(2.0, 0.0, 0.0));
+        assert_eq!(triangle.closest_point(Vector3::new_comp(-2.0, 1.0, 0.0)), Vector3::new_comp(0.0, 1.0, 0.0));
+        assert_eq!(triangle.closest_point(Vector3::new_comp(3.0, 3.0, 0.0)), Vector3::new_comp(2.0, 2.0, 0.0));
+
+        // face interior
+        assert_eq!(triangle.closest_point(Vector3::new_comp(1.0, 1.0, 5.0)), Vector3::new_comp(1.0, 1.0, 0.0));
+        assert_eq!(triangle.closest_point(Vector3::new_comp(1.0, 2.0, -1.0)), Vector3::new_comp(1.0, 2.0, 0.0));
+    }
+}
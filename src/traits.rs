@@ -1,4 +1,4 @@
-use num_traits::Float;
+use num_traits::{Float, One};
 
 #[cfg(feature = "half")]
 use half::{f16, bf16};
@@ -7,6 +7,26 @@ pub trait Pi<Output = Self> {
     type Output: Float;
     
     fn pi() -> Self::Output;
+
+    #[inline]
+    fn tau() -> Self::Output {
+        Self::pi() + Self::pi()
+    }
+
+    #[inline]
+    fn half_pi() -> Self::Output {
+        Self::pi() / (Self::Output::one() + Self::Output::one())
+    }
+
+    #[inline]
+    fn quarter_pi() -> Self::Output {
+        Self::half_pi() / (Self::Output::one() + Self::Output::one())
+    }
+
+    #[inline]
+    fn frac_1_pi() -> Self::Output {
+        Self::Output::one() / Self::pi()
+    }
 }
 
 #[cfg(feature = "half")]
@@ -14,7 +34,19 @@ impl Pi for f16 {
     type Output = f16;
 
     fn pi() -> Self::Output {
-        f16::from_f32(3.141592653589793238462643383279502884)
+        f16::PI
+    }
+
+    fn half_pi() -> Self::Output {
+        f16::FRAC_PI_2
+    }
+
+    fn quarter_pi() -> Self::Output {
+        f16::FRAC_PI_4
+    }
+
+    fn frac_1_pi() -> Self::Output {
+        f16::FRAC_1_PI
     }
 }
 
@@ -23,7 +55,19 @@ impl Pi for bf16 {
     type Output = bf16;
 
     fn pi() -> Self::Output {
-        bf16::from_f32(3.141592653589793238462643383279502884)
+        bf16::PI
+    }
+
+    fn half_pi() -> Self::Output {
+        bf16::FRAC_PI_2
+    }
+
+    fn quarter_pi() -> Self::Output {
+        bf16::FRAC_PI_4
+    }
+
+    fn frac_1_pi() -> Self::Output {
+        bf16::FRAC_1_PI
     }
 }
 
@@ -32,7 +76,27 @@ impl Pi for f32 {
     
     #[inline]
     fn pi() -> f32 {
-        3.141592653589793238462643383279502884
+        std::f32::consts::PI
+    }
+
+    #[inline]
+    fn tau() -> f32 {
+        std::f32::consts::TAU
+    }
+
+    #[inline]
+    fn half_pi() -> f32 {
+        std::f32::consts::FRAC_PI_2
+    }
+
+    #[inline]
+    fn quarter_pi() -> f32 {
+        std::f32::consts::FRAC_PI_4
+    }
+
+    #[inline]
+    fn frac_1_pi() -> f32 {
+        std::f32::consts::FRAC_1_PI
     }
 }
 
@@ -41,6 +105,49 @@ impl Pi for f64 {
     
     #[inline]
     fn pi() -> f64 {
-        3.141592653589793238462643383279502884
+        std::f64::consts::PI
+    }
+
+    #[inline]
+    fn tau() -> f64 {
+        std::f64::consts::TAU
+    }
+
+    #[inline]
+    fn half_pi() -> f64 {
+        std::f64::consts::FRAC_PI_2
+    }
+
+    #[inline]
+    fn quarter_pi() -> f64 {
+        std::f64::consts::FRAC_PI_4
+    }
+
+    #[inline]
+    fn frac_1_pi() -> f64 {
+        std::f64::consts::FRAC_1_PI
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pi_constants() {
+        assert_eq!(f64::tau(), 2.0 * f64::pi());
+        assert_eq!(f64::half_pi(), f64::pi() / 2.0);
+        assert_eq!(f64::quarter_pi(), f64::pi() / 4.0);
+        assert_eq!(f64::frac_1_pi(), 1.0 / f64::pi());
+        assert_eq!(f32::tau(), 2.0 * f32::pi());
+        assert_eq!(f32::half_pi(), f32::pi() / 2.0);
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn pi_constants_half() {
+        assert_eq!(f16::tau(), f16::from_f32(2.0) * f16::pi());
+        assert_eq!(f16::half_pi().to_f32(), f16::from_f32(std::f32::consts::FRAC_PI_2).to_f32());
+        assert_eq!(bf16::tau(), bf16::from_f32(2.0) * bf16::pi());
     }
 }
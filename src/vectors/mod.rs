@@ -1,5 +1,8 @@
-use std::ops::{DivAssign, Add, Mul, Neg, Index, IndexMut, Sub, Div, AddAssign, SubAssign, MulAssign, Deref};
-use num_traits::{real::Real, Float};
+use std::iter::{Sum, Product};
+use std::ops::{DivAssign, Add, Mul, Neg, Index, IndexMut, Sub, Div, AddAssign, SubAssign, MulAssign};
+use num_traits::{real::Real, Float, NumCast, Zero, One};
+
+use crate::interpolate::interpolate;
 
 #[cfg(feature = "half")]
 use half::{f16, bf16};
@@ -12,6 +15,21 @@ pub trait Vector: Index<usize> + IndexMut<usize> + Neg + Add + Sub + Mul + Div +
     fn get_at(&self, index: usize) -> Option<<Self as Vector>::Component>;
 }
 
+/// Returned when converting a slice whose length does not match the vector's dimension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LengthError {
+    pub expected: usize,
+    pub actual: usize,
+}
+
+impl std::fmt::Display for LengthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expected {} components, got {}", self.expected, self.actual)
+    }
+}
+
+impl std::error::Error for LengthError {}
+
 #[cfg(feature = "half")]
 pub type Vector2f16 = Vector2<f16>;
 #[cfg(feature = "half")]
@@ -31,11 +49,22 @@ pub type Vector2u64 = Vector2<u64>;
 pub type Vector2u128 = Vector2<u128>;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[repr(C)]
 pub struct Vector2<T> {
     pub x: T,
     pub y: T
 }
 
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Vector2<f32> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Vector2<f32> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Vector2<f64> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Vector2<f64> {}
+
 impl<T> Vector2<T> {
     #[inline]
     fn new() -> Self
@@ -78,6 +107,8 @@ impl<T> Vector2<T> {
         self.y = y;
     }
 
+    /// Divides by the magnitude unchecked, so a zero vector yields NaN components.
+    /// Use [`Self::try_normalized`] or [`Self::normalized_or_zero`] when that can happen.
     #[inline]
     pub fn normalized(&self) -> Self
     where T: Real + DivAssign {
@@ -134,11 +165,22 @@ impl<T> Vector2<T> {
         left.x * right.x + left.y * right.y
     }
 
+    /// Reflects `direction` off the surface with `normal`, which must be unit length.
+    /// Use [`Vector2::reflect_unnormalized`] when it might not be.
     #[inline]
     pub fn reflect(direction: Self, normal: Self) -> Self
     where T: Real + Copy {
         let factor = Self::dot(direction, normal) * (T::one() + T::one());
-        Self {x: normal.x * factor + direction.x, y: normal.y * factor + direction.y }
+        Self {x: direction.x - normal.x * factor, y: direction.y - normal.y * factor }
+    }
+
+    /// Like [`Vector2::reflect`], but divides by the squared length of `normal` so any non-zero
+    /// normal gives the same result as its normalized counterpart.
+    #[inline]
+    pub fn reflect_unnormalized(direction: Self, normal: Self) -> Self
+    where T: Real + Copy {
+        let factor = Self::dot(direction, normal) * (T::one() + T::one()) / normal.sqr_magnitude();
+        direction - normal * factor
     }
 
     #[inline]
@@ -168,6 +210,355 @@ impl<T> Vector2<T> {
     where T: Neg<Output = T> {
         Self { x: -vector.y, y: vector.x }
     }
+
+    /// Assumes `normal` is unit length, like [`Vector2::reflect`].
+    #[inline]
+    pub fn bounce(velocity: Self, normal: Self, restitution: T) -> Self
+    where T: Real {
+        let normal_velocity = normal * Self::dot(velocity, normal);
+        let tangent_velocity = velocity - normal_velocity;
+        tangent_velocity - normal_velocity * restitution
+    }
+
+    #[inline]
+    pub fn lerp(a: Self, b: Self, t: T) -> Self
+    where T: Real {
+        Self { x: interpolate(a.x, b.x, t), y: interpolate(a.y, b.y, t) }
+    }
+
+    #[inline]
+    pub fn lerp_clamped(a: Self, b: Self, t: T) -> Self
+    where T: Real {
+        Self::lerp(a, b, t.max(T::zero()).min(T::one()))
+    }
+
+    #[inline]
+    pub fn rotate(self, radians: T) -> Self
+    where T: Real {
+        let (sin, cos) = radians.sin_cos();
+        Self { x: self.x * cos - self.y * sin, y: self.x * sin + self.y * cos }
+    }
+
+    #[inline]
+    pub fn rotate_around(self, pivot: Self, radians: T) -> Self
+    where T: Real {
+        (self - pivot).rotate(radians) + pivot
+    }
+
+    #[inline]
+    pub fn min(a: Self, b: Self) -> Self
+    where T: PartialOrd + Copy {
+        Self { x: if a.x < b.x { a.x } else { b.x }, y: if a.y < b.y { a.y } else { b.y } }
+    }
+
+    #[inline]
+    pub fn max(a: Self, b: Self) -> Self
+    where T: PartialOrd + Copy {
+        Self { x: if a.x > b.x { a.x } else { b.x }, y: if a.y > b.y { a.y } else { b.y } }
+    }
+
+    #[inline]
+    pub fn abs(self) -> Self
+    where T: Real {
+        Self { x: self.x.abs(), y: self.y.abs() }
+    }
+
+    #[inline]
+    pub fn floor(self) -> Self
+    where T: Real {
+        Self { x: self.x.floor(), y: self.y.floor() }
+    }
+
+    #[inline]
+    pub fn ceil(self) -> Self
+    where T: Real {
+        Self { x: self.x.ceil(), y: self.y.ceil() }
+    }
+
+    #[inline]
+    pub fn round(self) -> Self
+    where T: Real {
+        Self { x: self.x.round(), y: self.y.round() }
+    }
+
+    #[inline]
+    pub fn clamp_magnitude(self, max: T) -> Self
+    where T: Real {
+        let sqr_magnitude = self.sqr_magnitude();
+
+        if sqr_magnitude > max * max {
+            self * (max / sqr_magnitude.sqrt())
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    pub fn project(vector: Self, normal: Self) -> Self
+    where T: Add<Output = T> + Mul<Output = T> + Div<Output = T> + Copy {
+        normal * Self::dot(vector, normal) / normal.sqr_magnitude()
+    }
+
+    #[inline]
+    pub fn reject(vector: Self, normal: Self) -> Self
+    where T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Copy {
+        vector - Self::project(vector, normal)
+    }
+
+    #[inline]
+    pub fn catmull_rom(p0: Self, p1: Self, p2: Self, p3: Self, t: T) -> Self
+    where T: Real {
+        let two = T::one() + T::one();
+        let three = two + T::one();
+        let four = two + two;
+        let five = four + T::one();
+
+        let t2 = t * t;
+        let t3 = t2 * t;
+
+        (p1 * two
+            + (p2 - p0) * t
+            + (p0 * two - p1 * five + p2 * four - p3) * t2
+            + (p1 * three - p0 - p2 * three + p3) * t3) / two
+    }
+
+    #[inline]
+    pub fn bezier3(a: Self, b: Self, c: Self, t: T) -> Self
+    where T: Real {
+        if t == T::one() {
+            return c;
+        }
+
+        Self::lerp(Self::lerp(a, b, t), Self::lerp(b, c, t), t)
+    }
+
+    #[inline]
+    pub fn bezier4(a: Self, b: Self, c: Self, d: Self, t: T) -> Self
+    where T: Real {
+        if t == T::one() {
+            return d;
+        }
+
+        let ab = Self::lerp(a, b, t);
+        let bc = Self::lerp(b, c, t);
+        let cd = Self::lerp(c, d, t);
+        Self::lerp(Self::lerp(ab, bc, t), Self::lerp(bc, cd, t), t)
+    }
+
+    #[inline]
+    pub fn approx_eq(self, other: Self, epsilon: T) -> bool
+    where T: Real {
+        (self.x - other.x).abs() <= epsilon && (self.y - other.y).abs() <= epsilon
+    }
+
+    #[inline]
+    pub fn try_normalized(&self) -> Option<Self>
+    where T: Real {
+        let magnitude = self.magnitude();
+
+        if magnitude > T::zero() {
+            Some(*self / magnitude)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn normalized_or_zero(&self) -> Self
+    where T: Real {
+        self.try_normalized().unwrap_or(Self { x: T::zero(), y: T::zero() })
+    }
+
+    #[inline]
+    pub fn extend(self, z: T) -> Vector3<T> {
+        Vector3::new_comp(self.x, self.y, z)
+    }
+
+    #[inline]
+    pub fn yx(self) -> Vector2<T>
+    where T: Copy {
+        Vector2::new_comp(self.y, self.x)
+    }
+
+    /// Clamps each component into `[min, max]`. Where `min` exceeds `max` on an axis, that component is set to `max`.
+    #[inline]
+    pub fn clamp(self, min: Self, max: Self) -> Self
+    where T: PartialOrd + Copy {
+        Self::min(Self::max(self, min), max)
+    }
+
+    #[inline]
+    pub fn angle(from: Self, to: Self) -> T
+    where T: Real {
+        let denominator = (from.sqr_magnitude() * to.sqr_magnitude()).sqrt();
+
+        if denominator == T::zero() {
+            return T::zero();
+        }
+
+        let cos = Self::dot(from, to) / denominator;
+        cos.max(-T::one()).min(T::one()).acos()
+    }
+
+    #[inline]
+    pub fn angle_degrees(from: Self, to: Self) -> T
+    where T: Real {
+        Self::angle(from, to).to_degrees()
+    }
+
+    #[inline]
+    pub fn sum<I: IntoIterator<Item = Self>>(iter: I) -> Self
+    where T: Zero {
+        iter.into_iter().sum()
+    }
+
+    /// Component-wise mean of the vectors, or `None` when `iter` is empty.
+    #[inline]
+    pub fn average<I: IntoIterator<Item = Self>>(iter: I) -> Option<Self>
+    where T: Real {
+        let mut count = 0usize;
+        let sum = Self::sum(iter.into_iter().inspect(|_| count += 1));
+
+        if count == 0 {
+            return None;
+        }
+
+        Some(sum / T::from(count)?)
+    }
+
+    /// Mirrors `self` across the line through the origin along `line_direction`,
+    /// which does not need to be normalized.
+    #[inline]
+    pub fn reflect_across(self, line_direction: Self) -> Self
+    where T: Real {
+        Self::project(self, line_direction) * (T::one() + T::one()) - self
+    }
+
+    /// Unit vector `radians` counter-clockwise from [`Vector2::right`].
+    #[inline]
+    pub fn from_angle(radians: T) -> Self
+    where T: Real {
+        let (sin, cos) = radians.sin_cos();
+        Self { x: cos, y: sin }
+    }
+
+    #[inline]
+    pub fn from_polar(radius: T, radians: T) -> Self
+    where T: Real {
+        Self::from_angle(radians) * radius
+    }
+
+    /// Angle from [`Vector2::right`] in `(-PI, PI]`, the inverse of [`Vector2::from_angle`].
+    #[inline]
+    pub fn to_angle(self) -> T
+    where T: Real {
+        self.y.atan2(self.x)
+    }
+
+    /// Converts each component with [`NumCast`], returning `None` when one of them
+    /// cannot be represented in `U`. Narrowing between float types rounds like `as`,
+    /// so out-of-range values become infinite instead.
+    #[inline]
+    pub fn cast<U>(self) -> Option<Vector2<U>>
+    where T: NumCast, U: NumCast {
+        Some(Vector2 { x: U::from(self.x)?, y: U::from(self.y)? })
+    }
+
+    /// Index of the largest component. Ties go to the lowest index.
+    #[inline]
+    pub fn max_axis(self) -> usize
+    where T: PartialOrd + Copy {
+        let components = [self.x, self.y];
+        (1..components.len()).fold(0, |axis, i| if components[i] > components[axis] { i } else { axis })
+    }
+
+    /// Index of the smallest component. Ties go to the lowest index.
+    #[inline]
+    pub fn min_axis(self) -> usize
+    where T: PartialOrd + Copy {
+        let components = [self.x, self.y];
+        (1..components.len()).fold(0, |axis, i| if components[i] < components[axis] { i } else { axis })
+    }
+
+    #[inline]
+    pub fn max_component(self) -> T
+    where T: PartialOrd + Copy {
+        [self.x, self.y][self.max_axis()]
+    }
+
+    #[inline]
+    pub fn min_component(self) -> T
+    where T: PartialOrd + Copy {
+        [self.x, self.y][self.min_axis()]
+    }
+
+    /// Rounds each component to the nearest multiple of the matching `cell` component.
+    /// Components with a cell size of zero are left unchanged.
+    #[inline]
+    pub fn snap_to_grid(self, cell: Self) -> Self
+    where T: Real {
+        Self { x: snap(self.x, cell.x, T::round), y: snap(self.y, cell.y, T::round) }
+    }
+
+    #[inline]
+    pub fn snap_to_grid_uniform(self, cell: T) -> Self
+    where T: Real {
+        self.snap_to_grid(Self::new_comp(cell, cell))
+    }
+
+    /// Like [`Self::snap_to_grid`], but rounds down to the cell containing each component.
+    #[inline]
+    pub fn snap_floor(self, cell: Self) -> Self
+    where T: Real {
+        Self { x: snap(self.x, cell.x, T::floor), y: snap(self.y, cell.y, T::floor) }
+    }
+
+    /// Whether every component is neither infinite nor NaN.
+    #[inline]
+    pub fn is_finite(&self) -> bool
+    where T: Float {
+        self.x.is_finite() && self.y.is_finite()
+    }
+
+    #[inline]
+    pub fn has_nan(&self) -> bool
+    where T: Float {
+        self.x.is_nan() || self.y.is_nan()
+    }
+
+    /// The z component of the 3D cross product of `a` and `b`, positive when `b` lies
+    /// counter-clockwise of `a`.
+    #[inline]
+    pub fn perp_dot(a: Self, b: Self) -> T
+    where T: Sub<Output = T> + Mul<Output = T> + Copy {
+        a.x * b.y - a.y * b.x
+    }
+
+    #[inline]
+    pub fn map<U>(self, f: impl Fn(T) -> U) -> Vector2<U> {
+        Vector2 { x: f(self.x), y: f(self.y) }
+    }
+
+    /// Applies `f` to each pair of matching components of `self` and `other`.
+    #[inline]
+    pub fn zip_map<U, V>(self, other: Vector2<U>, f: impl Fn(T, U) -> V) -> Vector2<V> {
+        Vector2 { x: f(self.x, other.x), y: f(self.y, other.y) }
+    }
+
+    /// True when the perp-dot of `self` and `other` is within `epsilon` of zero. The vectors are
+    /// not normalized, so `epsilon` scales with their lengths.
+    #[inline]
+    pub fn is_parallel(&self, other: &Self, epsilon: T) -> bool
+    where T: Real {
+        Self::perp_dot(*self, *other).abs() <= epsilon
+    }
+
+    /// True when the dot product of `self` and `other` is within `epsilon` of zero.
+    #[inline]
+    pub fn is_perpendicular(&self, other: &Self, epsilon: T) -> bool
+    where T: Real {
+        Self::dot(*self, *other).abs() <= epsilon
+    }
 }
 
 impl<T> Vector for Vector2<T>
@@ -314,12 +705,12 @@ where T: Mul<Output = T> {
 }
 
 impl<'a, T> Mul<&'a Vector2<T>> for Vector2<T>
-where T: Mul<Output = T> + Deref<Target = T> + Copy {
+where T: Mul<Output = T> + Copy {
     type Output = Self;
 
     #[inline]
     fn mul(self, rhs: &'a Vector2<T>) -> Self::Output {
-        Self { x: self.x * *rhs.x, y: self.y * *rhs.y }
+        Self { x: self.x * rhs.x, y: self.y * rhs.y }
     }
 }
 
@@ -354,12 +745,12 @@ where T: Div<Output = T> {
 }
 
 impl<'a, T> Div<&'a Vector2<T>> for Vector2<T>
-where T: Div<Output = T> + Deref<Target = T> + Copy {
+where T: Div<Output = T> + Copy {
     type Output = Self;
 
     #[inline]
     fn div(self, rhs: &'a Vector2<T>) -> Self::Output {
-        Self { x: self.x / *rhs.x, y: self.y / *rhs.y }
+        Self { x: self.x / rhs.x, y: self.y / rhs.y }
     }
 }
 
@@ -427,11 +818,11 @@ where T: MulAssign {
 }
 
 impl<'a, T> MulAssign<&'a Vector2<T>> for Vector2<T>
-where T: MulAssign + Deref<Target = T> + Copy {
+where T: MulAssign + Copy {
     #[inline]
     fn mul_assign(&mut self, rhs: &'a Vector2<T>) {
-        self.x *= *rhs.x;
-        self.y *= *rhs.y;
+        self.x *= rhs.x;
+        self.y *= rhs.y;
     }
 }
 
@@ -463,11 +854,11 @@ where T: DivAssign {
 }
 
 impl<'a, T> DivAssign<&'a Vector2<T>> for Vector2<T>
-where T: DivAssign + Deref<Target = T> + Copy {
+where T: DivAssign + Copy {
     #[inline]
     fn div_assign(&mut self, rhs: &'a Vector2<T>) {
-        self.x /= *rhs.x;
-        self.y /= *rhs.y;
+        self.x /= rhs.x;
+        self.y /= rhs.y;
     }
 }
 
@@ -486,11 +877,23 @@ where T: Copy {
     }
 }
 
+impl<T> TryFrom<&[T]> for Vector2<T>
+where T: Copy {
+    type Error = LengthError;
+
+    #[inline]
+    fn try_from(slice: &[T]) -> Result<Self, Self::Error> {
+        <[T; 2]>::try_from(slice)
+            .map(Self::from)
+            .map_err(|_| LengthError { expected: 2, actual: slice.len() })
+    }
+}
+
 impl<T> From<[[T; 1]; 2]> for Vector2<T>
 where T: Copy {
     #[inline]
     fn from(array: [[T; 1]; 2]) -> Self {
-        Self { x: array[0][0], y: array[0][1] }
+        Self { x: array[0][0], y: array[1][0] }
     }
 }
 
@@ -531,6 +934,68 @@ impl<T> From<Vector4<T>> for Vector2<T> {
     }
 }
 
+#[cfg(feature = "mint")]
+impl<T> From<mint::Vector2<T>> for Vector2<T> {
+    #[inline]
+    fn from(value: mint::Vector2<T>) -> Self {
+        Self { x: value.x, y: value.y }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<T> From<Vector2<T>> for mint::Vector2<T> {
+    #[inline]
+    fn from(vector: Vector2<T>) -> Self {
+        Self { x: vector.x, y: vector.y }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<T> From<mint::Point2<T>> for Vector2<T> {
+    #[inline]
+    fn from(value: mint::Point2<T>) -> Self {
+        Self { x: value.x, y: value.y }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<T> From<Vector2<T>> for mint::Point2<T> {
+    #[inline]
+    fn from(vector: Vector2<T>) -> Self {
+        Self { x: vector.x, y: vector.y }
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> approx::AbsDiffEq for Vector2<T>
+where T: approx::AbsDiffEq, T::Epsilon: Copy {
+    type Epsilon = T::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        T::abs_diff_eq(&self.x, &other.x, epsilon) && T::abs_diff_eq(&self.y, &other.y, epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> approx::RelativeEq for Vector2<T>
+where T: approx::RelativeEq, T::Epsilon: Copy {
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+        T::relative_eq(&self.x, &other.x, epsilon, max_relative) && T::relative_eq(&self.y, &other.y, epsilon, max_relative)
+    }
+}
+
 impl<T> IntoIterator for Vector2<T> {
     type Item = T;
     
@@ -560,9 +1025,25 @@ where T: Real {
     }
 }
 
-#[cfg(feature = "half")]
-pub type Vector3f16 = Vector3<f16>;
-#[cfg(feature = "half")]
+impl<T> Sum for Vector2<T>
+where T: Zero {
+    #[inline]
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self { x: T::zero(), y: T::zero() }, |acc, vector| acc + vector)
+    }
+}
+
+impl<T> Product for Vector2<T>
+where T: One {
+    #[inline]
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self { x: T::one(), y: T::one() }, |acc, vector| acc * vector)
+    }
+}
+
+#[cfg(feature = "half")]
+pub type Vector3f16 = Vector3<f16>;
+#[cfg(feature = "half")]
 pub type Vector3bf16 = Vector3<bf16>;
 
 pub type Vector3f32 = Vector3<f32>;
@@ -579,12 +1060,23 @@ pub type Vector3u64 = Vector3<u64>;
 pub type Vector3u128 = Vector3<u128>;
 
 #[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[repr(C)]
 pub struct Vector3<T> {
     pub x: T,
     pub y: T,
     pub z: T
 }
 
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Vector3<f32> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Vector3<f32> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Vector3<f64> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Vector3<f64> {}
+
 impl<T> Vector3<T> {
     fn new() -> Self
     where T: Default {
@@ -639,6 +1131,8 @@ impl<T> Vector3<T> {
         self.z = z;
     }
 
+    /// Divides by the magnitude unchecked, so a zero vector yields NaN components.
+    /// Use [`Self::try_normalized`] or [`Self::normalized_or_zero`] when that can happen.
     #[inline]
     pub fn normalized(&self) -> Self
     where T: Real + DivAssign {
@@ -697,11 +1191,22 @@ impl<T> Vector3<T> {
         left.x * right.x + left.y * right.y + left.z * right.z
     }
 
+    /// Reflects `direction` off the surface with `normal`, which must be unit length.
+    /// Use [`Vector3::reflect_unnormalized`] when it might not be.
     #[inline]
     pub fn reflect(direction: Self, normal: Self) -> Self
     where T: Real + Copy {
         let factor = Self::dot(direction, normal) * (T::one() + T::one());
-        Self {x: normal.x * factor + direction.x, y: normal.y * factor + direction.y, z: normal.z * factor + direction.z }
+        Self {x: direction.x - normal.x * factor, y: direction.y - normal.y * factor, z: direction.z - normal.z * factor }
+    }
+
+    /// Like [`Vector3::reflect`], but divides by the squared length of `normal` so any non-zero
+    /// normal gives the same result as its normalized counterpart.
+    #[inline]
+    pub fn reflect_unnormalized(direction: Self, normal: Self) -> Self
+    where T: Real + Copy {
+        let factor = Self::dot(direction, normal) * (T::one() + T::one()) / normal.sqr_magnitude();
+        direction - normal * factor
     }
 
     #[inline]
@@ -756,6 +1261,370 @@ impl<T> Vector3<T> {
     where T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Div<Output = T> + Copy {
         vector - Self::project(vector, plane_normal)
     }
+
+    #[inline]
+    pub fn lerp(a: Self, b: Self, t: T) -> Self
+    where T: Real {
+        Self { x: interpolate(a.x, b.x, t), y: interpolate(a.y, b.y, t), z: interpolate(a.z, b.z, t) }
+    }
+
+    #[inline]
+    pub fn lerp_clamped(a: Self, b: Self, t: T) -> Self
+    where T: Real {
+        Self::lerp(a, b, t.max(T::zero()).min(T::one()))
+    }
+
+    #[inline]
+    pub fn min(a: Self, b: Self) -> Self
+    where T: PartialOrd + Copy {
+        Self { x: if a.x < b.x { a.x } else { b.x }, y: if a.y < b.y { a.y } else { b.y }, z: if a.z < b.z { a.z } else { b.z } }
+    }
+
+    #[inline]
+    pub fn max(a: Self, b: Self) -> Self
+    where T: PartialOrd + Copy {
+        Self { x: if a.x > b.x { a.x } else { b.x }, y: if a.y > b.y { a.y } else { b.y }, z: if a.z > b.z { a.z } else { b.z } }
+    }
+
+    #[inline]
+    pub fn abs(self) -> Self
+    where T: Real {
+        Self { x: self.x.abs(), y: self.y.abs(), z: self.z.abs() }
+    }
+
+    #[inline]
+    pub fn floor(self) -> Self
+    where T: Real {
+        Self { x: self.x.floor(), y: self.y.floor(), z: self.z.floor() }
+    }
+
+    #[inline]
+    pub fn ceil(self) -> Self
+    where T: Real {
+        Self { x: self.x.ceil(), y: self.y.ceil(), z: self.z.ceil() }
+    }
+
+    #[inline]
+    pub fn round(self) -> Self
+    where T: Real {
+        Self { x: self.x.round(), y: self.y.round(), z: self.z.round() }
+    }
+
+    #[inline]
+    pub fn clamp_magnitude(self, max: T) -> Self
+    where T: Real {
+        let sqr_magnitude = self.sqr_magnitude();
+
+        if sqr_magnitude > max * max {
+            self * (max / sqr_magnitude.sqrt())
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    pub fn reject(vector: Self, normal: Self) -> Self
+    where T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Copy {
+        vector - Self::project(vector, normal)
+    }
+
+    #[inline]
+    pub fn catmull_rom(p0: Self, p1: Self, p2: Self, p3: Self, t: T) -> Self
+    where T: Real {
+        let two = T::one() + T::one();
+        let three = two + T::one();
+        let four = two + two;
+        let five = four + T::one();
+
+        let t2 = t * t;
+        let t3 = t2 * t;
+
+        (p1 * two
+            + (p2 - p0) * t
+            + (p0 * two - p1 * five + p2 * four - p3) * t2
+            + (p1 * three - p0 - p2 * three + p3) * t3) / two
+    }
+
+    #[inline]
+    pub fn bezier3(a: Self, b: Self, c: Self, t: T) -> Self
+    where T: Real {
+        if t == T::one() {
+            return c;
+        }
+
+        Self::lerp(Self::lerp(a, b, t), Self::lerp(b, c, t), t)
+    }
+
+    #[inline]
+    pub fn bezier4(a: Self, b: Self, c: Self, d: Self, t: T) -> Self
+    where T: Real {
+        if t == T::one() {
+            return d;
+        }
+
+        let ab = Self::lerp(a, b, t);
+        let bc = Self::lerp(b, c, t);
+        let cd = Self::lerp(c, d, t);
+        Self::lerp(Self::lerp(ab, bc, t), Self::lerp(bc, cd, t), t)
+    }
+
+    #[inline]
+    pub fn approx_eq(self, other: Self, epsilon: T) -> bool
+    where T: Real {
+        (self.x - other.x).abs() <= epsilon && (self.y - other.y).abs() <= epsilon && (self.z - other.z).abs() <= epsilon
+    }
+
+    #[inline]
+    pub fn any_orthogonal(self) -> Self
+    where T: Real {
+        if self.x.abs() > self.z.abs() {
+            Self { x: -self.y, y: self.x, z: T::zero() }
+        } else {
+            Self { x: T::zero(), y: -self.z, z: self.y }
+        }
+    }
+
+    /// Returns `(self normalized, tangent, bitangent)` where `cross(normal, tangent) == bitangent`.
+    #[inline]
+    pub fn orthonormal_basis(self) -> (Self, Self, Self)
+    where T: Real + DivAssign {
+        let normal = self.normalized();
+        let tangent = normal.any_orthogonal().normalized();
+        let bitangent = Self::cross(normal, tangent);
+        (normal, tangent, bitangent)
+    }
+
+    #[inline]
+    pub fn try_normalized(&self) -> Option<Self>
+    where T: Real {
+        let magnitude = self.magnitude();
+
+        if magnitude > T::zero() {
+            Some(*self / magnitude)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn normalized_or_zero(&self) -> Self
+    where T: Real {
+        self.try_normalized().unwrap_or(Self { x: T::zero(), y: T::zero(), z: T::zero() })
+    }
+
+    #[inline]
+    pub fn extend(self, w: T) -> Vector4<T> {
+        Vector4::new_comp(self.x, self.y, self.z, w)
+    }
+
+    #[inline]
+    pub fn truncate(self) -> Vector2<T> {
+        Vector2::new_comp(self.x, self.y)
+    }
+
+    #[inline]
+    pub fn xy(self) -> Vector2<T>
+    where T: Copy {
+        Vector2::new_comp(self.x, self.y)
+    }
+
+    #[inline]
+    pub fn xz(self) -> Vector2<T>
+    where T: Copy {
+        Vector2::new_comp(self.x, self.z)
+    }
+
+    #[inline]
+    pub fn yz(self) -> Vector2<T>
+    where T: Copy {
+        Vector2::new_comp(self.y, self.z)
+    }
+
+    #[inline]
+    pub fn yx(self) -> Vector2<T>
+    where T: Copy {
+        Vector2::new_comp(self.y, self.x)
+    }
+
+    #[inline]
+    pub fn zyx(self) -> Vector3<T>
+    where T: Copy {
+        Vector3::new_comp(self.z, self.y, self.x)
+    }
+
+    /// Clamps each component into `[min, max]`. Where `min` exceeds `max` on an axis, that component is set to `max`.
+    #[inline]
+    pub fn clamp(self, min: Self, max: Self) -> Self
+    where T: PartialOrd + Copy {
+        Self::min(Self::max(self, min), max)
+    }
+
+    #[inline]
+    pub fn angle(from: Self, to: Self) -> T
+    where T: Real {
+        let denominator = (from.sqr_magnitude() * to.sqr_magnitude()).sqrt();
+
+        if denominator == T::zero() {
+            return T::zero();
+        }
+
+        let cos = Self::dot(from, to) / denominator;
+        cos.max(-T::one()).min(T::one()).acos()
+    }
+
+    #[inline]
+    pub fn angle_degrees(from: Self, to: Self) -> T
+    where T: Real {
+        Self::angle(from, to).to_degrees()
+    }
+
+    #[inline]
+    pub fn sum<I: IntoIterator<Item = Self>>(iter: I) -> Self
+    where T: Zero {
+        iter.into_iter().sum()
+    }
+
+    /// Component-wise mean of the vectors, or `None` when `iter` is empty.
+    #[inline]
+    pub fn average<I: IntoIterator<Item = Self>>(iter: I) -> Option<Self>
+    where T: Real {
+        let mut count = 0usize;
+        let sum = Self::sum(iter.into_iter().inspect(|_| count += 1));
+
+        if count == 0 {
+            return None;
+        }
+
+        Some(sum / T::from(count)?)
+    }
+
+    /// Interpolates along the great-circle arc between two unit-length directions.
+    /// Nearly parallel (or opposite) inputs have no well-defined arc and fall back to [`Self::lerp`].
+    #[inline]
+    pub fn slerp(from: Self, to: Self, t: T) -> Self
+    where T: Real {
+        let cos = Self::dot(from, to).max(-T::one()).min(T::one());
+        let theta = cos.acos();
+        let sin = theta.sin();
+
+        if sin.abs() <= T::epsilon().sqrt() {
+            return Self::lerp(from, to, t);
+        }
+
+        (from * ((T::one() - t) * theta).sin() + to * (t * theta).sin()) / sin
+    }
+
+    /// Converts each component with [`NumCast`], returning `None` when one of them
+    /// cannot be represented in `U`. Narrowing between float types rounds like `as`,
+    /// so out-of-range values become infinite instead.
+    #[inline]
+    pub fn cast<U>(self) -> Option<Vector3<U>>
+    where T: NumCast, U: NumCast {
+        Some(Vector3 { x: U::from(self.x)?, y: U::from(self.y)?, z: U::from(self.z)? })
+    }
+
+    /// Index of the largest component. Ties go to the lowest index.
+    #[inline]
+    pub fn max_axis(self) -> usize
+    where T: PartialOrd + Copy {
+        let components = [self.x, self.y, self.z];
+        (1..components.len()).fold(0, |axis, i| if components[i] > components[axis] { i } else { axis })
+    }
+
+    /// Index of the smallest component. Ties go to the lowest index.
+    #[inline]
+    pub fn min_axis(self) -> usize
+    where T: PartialOrd + Copy {
+        let components = [self.x, self.y, self.z];
+        (1..components.len()).fold(0, |axis, i| if components[i] < components[axis] { i } else { axis })
+    }
+
+    #[inline]
+    pub fn max_component(self) -> T
+    where T: PartialOrd + Copy {
+        [self.x, self.y, self.z][self.max_axis()]
+    }
+
+    #[inline]
+    pub fn min_component(self) -> T
+    where T: PartialOrd + Copy {
+        [self.x, self.y, self.z][self.min_axis()]
+    }
+
+    /// Rounds each component to the nearest multiple of the matching `cell` component.
+    /// Components with a cell size of zero are left unchanged.
+    #[inline]
+    pub fn snap_to_grid(self, cell: Self) -> Self
+    where T: Real {
+        Self { x: snap(self.x, cell.x, T::round), y: snap(self.y, cell.y, T::round), z: snap(self.z, cell.z, T::round) }
+    }
+
+    #[inline]
+    pub fn snap_to_grid_uniform(self, cell: T) -> Self
+    where T: Real {
+        self.snap_to_grid(Self::new_comp(cell, cell, cell))
+    }
+
+    /// Like [`Self::snap_to_grid`], but rounds down to the cell containing each component.
+    #[inline]
+    pub fn snap_floor(self, cell: Self) -> Self
+    where T: Real {
+        Self { x: snap(self.x, cell.x, T::floor), y: snap(self.y, cell.y, T::floor), z: snap(self.z, cell.z, T::floor) }
+    }
+
+    /// Rotates by `radians` around `axis` with Rodrigues' formula, following the right-hand
+    /// rule like [`crate::matrices::Matrix4::from_rotation`]: rotating [`Vector3::right`] a
+    /// quarter turn around [`Vector3::up`] gives [`Vector3::back`]. `axis` is normalized here,
+    /// and a zero-length axis returns `self` unchanged.
+    #[inline]
+    pub fn rotate_around_axis(self, axis: Self, radians: T) -> Self
+    where T: Real {
+        let Some(axis) = axis.try_normalized() else {
+            return self;
+        };
+
+        let (sin, cos) = radians.sin_cos();
+        self * cos + Self::cross(axis, self) * sin + axis * (Self::dot(axis, self) * (T::one() - cos))
+    }
+
+    /// Whether every component is neither infinite nor NaN.
+    #[inline]
+    pub fn is_finite(&self) -> bool
+    where T: Float {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+
+    #[inline]
+    pub fn has_nan(&self) -> bool
+    where T: Float {
+        self.x.is_nan() || self.y.is_nan() || self.z.is_nan()
+    }
+
+    #[inline]
+    pub fn map<U>(self, f: impl Fn(T) -> U) -> Vector3<U> {
+        Vector3 { x: f(self.x), y: f(self.y), z: f(self.z) }
+    }
+
+    /// Applies `f` to each pair of matching components of `self` and `other`.
+    #[inline]
+    pub fn zip_map<U, V>(self, other: Vector3<U>, f: impl Fn(T, U) -> V) -> Vector3<V> {
+        Vector3 { x: f(self.x, other.x), y: f(self.y, other.y), z: f(self.z, other.z) }
+    }
+
+    /// True when the cross product of `self` and `other` has a magnitude within `epsilon`.
+    /// The vectors are not normalized, so `epsilon` scales with their lengths.
+    #[inline]
+    pub fn is_parallel(&self, other: &Self, epsilon: T) -> bool
+    where T: Real {
+        Self::cross(*self, *other).magnitude() <= epsilon
+    }
+
+    /// True when the dot product of `self` and `other` is within `epsilon` of zero.
+    #[inline]
+    pub fn is_perpendicular(&self, other: &Self, epsilon: T) -> bool
+    where T: Real {
+        Self::dot(*self, *other).abs() <= epsilon
+    }
 }
 
 impl<T> Vector for Vector3<T>
@@ -905,12 +1774,12 @@ where T: Mul<Output = T> {
 }
 
 impl<'a, T> Mul<&'a Vector3<T>> for Vector3<T>
-where T: Mul<Output = T> + Deref<Target = T> + Copy {
+where T: Mul<Output = T> + Copy {
     type Output = Self;
 
     #[inline]
     fn mul(self, rhs: &'a Vector3<T>) -> Self::Output {
-        Self { x: self.x * *rhs.x, y: self.y * *rhs.y, z: self.z * *rhs.z }
+        Self { x: self.x * rhs.x, y: self.y * rhs.y, z: self.z * rhs.z }
     }
 }
 
@@ -945,12 +1814,12 @@ where T: Div<Output = T> {
 }
 
 impl<'a, T> Div<&'a Vector3<T>> for Vector3<T>
-where T: Div<Output = T> + Deref<Target = T> + Copy {
+where T: Div<Output = T> + Copy {
     type Output = Self;
 
     #[inline]
     fn div(self, rhs: &'a Vector3<T>) -> Self::Output {
-        Self { x: self.x / *rhs.x, y: self.y / *rhs.y, z: self.z / *rhs.z }
+        Self { x: self.x / rhs.x, y: self.y / rhs.y, z: self.z / rhs.z }
     }
 }
 
@@ -960,6 +1829,7 @@ where T: AddAssign {
     fn add_assign(&mut self, rhs: Self) {
         self.x += rhs.x;
         self.y += rhs.y;
+        self.z += rhs.z;
     }
 }
 
@@ -969,6 +1839,7 @@ where T: AddAssign + Copy {
     fn add_assign(&mut self, rhs: &'a Vector3<T>) {
         self.x += rhs.x;
         self.y += rhs.y;
+        self.z += rhs.z;
     }
 }
 
@@ -978,6 +1849,7 @@ where T: SubAssign {
     fn sub_assign(&mut self, rhs: Self) {
         self.x -= rhs.x;
         self.y -= rhs.y;
+        self.z -= rhs.z;
     }
 }
 
@@ -987,6 +1859,7 @@ where T: SubAssign + Copy {
     fn sub_assign(&mut self, rhs: &'a Vector3<T>) {
         self.x -= rhs.x;
         self.y -= rhs.y;
+        self.z -= rhs.z;
     }
 }
 
@@ -996,6 +1869,7 @@ where T: MulAssign + Copy {
     fn mul_assign(&mut self, rhs: T) {
         self.x *= rhs;
         self.y *= rhs;
+        self.z *= rhs;
     }
 }
 
@@ -1005,6 +1879,7 @@ where T: MulAssign + Copy {
     fn mul_assign(&mut self, rhs: &'a T) {
         self.x *= *rhs;
         self.y *= *rhs;
+        self.z *= *rhs;
     }
 }
 
@@ -1014,15 +1889,17 @@ where T: MulAssign {
     fn mul_assign(&mut self, rhs: Vector3<T>) {
         self.x *= rhs.x;
         self.y *= rhs.y;
+        self.z *= rhs.z;
     }
 }
 
 impl<'a, T> MulAssign<&'a Vector3<T>> for Vector3<T>
-where T: MulAssign + Deref<Target = T> + Copy {
+where T: MulAssign + Copy {
     #[inline]
     fn mul_assign(&mut self, rhs: &'a Vector3<T>) {
-        self.x *= *rhs.x;
-        self.y *= *rhs.y;
+        self.x *= rhs.x;
+        self.y *= rhs.y;
+        self.z *= rhs.z;
     }
 }
 
@@ -1032,6 +1909,7 @@ where T: DivAssign + Copy {
     fn div_assign(&mut self, rhs: T) {
         self.x /= rhs;
         self.y /= rhs;
+        self.z /= rhs;
     }
 }
 
@@ -1041,6 +1919,7 @@ where T: DivAssign + Copy {
     fn div_assign(&mut self, rhs: &'a T) {
         self.x /= *rhs;
         self.y /= *rhs;
+        self.z /= *rhs;
     }
 }
 
@@ -1050,15 +1929,17 @@ where T: DivAssign {
     fn div_assign(&mut self, rhs: Vector3<T>) {
         self.x /= rhs.x;
         self.y /= rhs.y;
+        self.z /= rhs.z;
     }
 }
 
 impl<'a, T> DivAssign<&'a Vector3<T>> for Vector3<T>
-where T: DivAssign + Deref<Target = T> + Copy {
+where T: DivAssign + Copy {
     #[inline]
     fn div_assign(&mut self, rhs: &'a Vector3<T>) {
-        self.x /= *rhs.x;
-        self.y /= *rhs.y;
+        self.x /= rhs.x;
+        self.y /= rhs.y;
+        self.z /= rhs.z;
     }
 }
 
@@ -1077,11 +1958,23 @@ where T: Copy {
     }
 }
 
+impl<T> TryFrom<&[T]> for Vector3<T>
+where T: Copy {
+    type Error = LengthError;
+
+    #[inline]
+    fn try_from(slice: &[T]) -> Result<Self, Self::Error> {
+        <[T; 3]>::try_from(slice)
+            .map(Self::from)
+            .map_err(|_| LengthError { expected: 3, actual: slice.len() })
+    }
+}
+
 impl<T> From<[[T; 1]; 3]> for Vector3<T>
 where T: Copy {
     #[inline]
     fn from(array: [[T; 1]; 3]) -> Self {
-        Self { x: array[0][0], y: array[0][1], z: array[0][2] }
+        Self { x: array[0][0], y: array[1][0], z: array[2][0] }
     }
 }
 
@@ -1122,39 +2015,117 @@ impl<T> From<Vector4<T>> for Vector3<T> {
     }
 }
 
-impl<T> IntoIterator for Vector3<T> {
-    type Item = T;
+#[cfg(feature = "mint")]
+impl<T> From<mint::Vector3<T>> for Vector3<T> {
+    #[inline]
+    fn from(value: mint::Vector3<T>) -> Self {
+        Self { x: value.x, y: value.y, z: value.z }
+    }
+}
 
-    type IntoIter = std::array::IntoIter<Self::Item, 2>;
+#[cfg(feature = "mint")]
+impl<T> From<Vector3<T>> for mint::Vector3<T> {
+    #[inline]
+    fn from(vector: Vector3<T>) -> Self {
+        Self { x: vector.x, y: vector.y, z: vector.z }
+    }
+}
 
+#[cfg(feature = "mint")]
+impl<T> From<mint::Point3<T>> for Vector3<T> {
     #[inline]
-    fn into_iter(self) -> Self::IntoIter {
-        [self.x, self.y].into_iter()
+    fn from(value: mint::Point3<T>) -> Self {
+        Self { x: value.x, y: value.y, z: value.z }
     }
 }
 
-impl<T> FromIterator<T> for Vector3<T>
-where T: Real {
+#[cfg(feature = "mint")]
+impl<T> From<Vector3<T>> for mint::Point3<T> {
     #[inline]
-    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-        let mut iter = iter.into_iter();
+    fn from(vector: Vector3<T>) -> Self {
+        Self { x: vector.x, y: vector.y, z: vector.z }
+    }
+}
 
-        if let Some(x) = iter.next() {
-            if let Some(y) = iter.next() {
-                if let Some(z) = iter.next() {
-                    return Self { x, y, z };
-                }
+#[cfg(feature = "approx")]
+impl<T> approx::AbsDiffEq for Vector3<T>
+where T: approx::AbsDiffEq, T::Epsilon: Copy {
+    type Epsilon = T::Epsilon;
 
-                return Self { x, y, z: T::zero() };
-            }
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
 
-            return Self { x, y: T::zero(), z: T::zero() };
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        T::abs_diff_eq(&self.x, &other.x, epsilon) && T::abs_diff_eq(&self.y, &other.y, epsilon) && T::abs_diff_eq(&self.z, &other.z, epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> approx::RelativeEq for Vector3<T>
+where T: approx::RelativeEq, T::Epsilon: Copy {
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+        T::relative_eq(&self.x, &other.x, epsilon, max_relative) && T::relative_eq(&self.y, &other.y, epsilon, max_relative) && T::relative_eq(&self.z, &other.z, epsilon, max_relative)
+    }
+}
+
+impl<T> IntoIterator for Vector3<T> {
+    type Item = T;
+
+    type IntoIter = std::array::IntoIter<Self::Item, 3>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        [self.x, self.y, self.z].into_iter()
+    }
+}
+
+impl<T> FromIterator<T> for Vector3<T>
+where T: Real {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut iter = iter.into_iter();
+
+        if let Some(x) = iter.next() {
+            if let Some(y) = iter.next() {
+                if let Some(z) = iter.next() {
+                    return Self { x, y, z };
+                }
+
+                return Self { x, y, z: T::zero() };
+            }
+
+            return Self { x, y: T::zero(), z: T::zero() };
         }
 
         Self { x: T::zero(), y: T::zero(), z: T::zero() }
     }
 }
 
+impl<T> Sum for Vector3<T>
+where T: Zero {
+    #[inline]
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self { x: T::zero(), y: T::zero(), z: T::zero() }, |acc, vector| acc + vector)
+    }
+}
+
+impl<T> Product for Vector3<T>
+where T: One {
+    #[inline]
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self { x: T::one(), y: T::one(), z: T::one() }, |acc, vector| acc * vector)
+    }
+}
+
 
 
 #[cfg(feature = "half")]
@@ -1176,6 +2147,7 @@ pub type Vector4u64 = Vector4<u64>;
 pub type Vector4u128 = Vector4<u128>;
 
 #[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[repr(C)]
 pub struct Vector4<T> {
     pub x: T,
     pub y: T,
@@ -1183,6 +2155,16 @@ pub struct Vector4<T> {
     pub w: T
 }
 
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Vector4<f32> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Vector4<f32> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Vector4<f64> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Vector4<f64> {}
+
 impl<T> Vector4<T> {
     fn new() -> Self
     where T: Default {
@@ -1250,6 +2232,8 @@ impl<T> Vector4<T> {
         self.w = w;
     }
 
+    /// Divides by the magnitude unchecked, so a zero vector yields NaN components.
+    /// Use [`Self::try_normalized`] or [`Self::normalized_or_zero`] when that can happen.
     #[inline]
     pub fn normalized(&self) -> Self
     where T: Real + DivAssign {
@@ -1310,11 +2294,22 @@ impl<T> Vector4<T> {
         left.x * right.x + left.y * right.y + left.z * right.z + left.w * right.w
     }
 
+    /// Reflects `direction` off the surface with `normal`, which must be unit length.
+    /// Use [`Vector4::reflect_unnormalized`] when it might not be.
     #[inline]
     pub fn reflect(direction: Self, normal: Self) -> Self
     where T: Real + Copy {
         let factor = Self::dot(direction, normal) * (T::one() + T::one());
-        Self {x: normal.x * factor + direction.x, y: normal.y * factor + direction.y, z: normal.z * factor + direction.z, w: normal.w * factor + direction.w }
+        Self {x: direction.x - normal.x * factor, y: direction.y - normal.y * factor, z: direction.z - normal.z * factor, w: direction.w - normal.w * factor }
+    }
+
+    /// Like [`Vector4::reflect`], but divides by the squared length of `normal` so any non-zero
+    /// normal gives the same result as its normalized counterpart.
+    #[inline]
+    pub fn reflect_unnormalized(direction: Self, normal: Self) -> Self
+    where T: Real + Copy {
+        let factor = Self::dot(direction, normal) * (T::one() + T::one()) / normal.sqr_magnitude();
+        direction - normal * factor
     }
 
     #[inline]
@@ -1346,6 +2341,318 @@ impl<T> Vector4<T> {
     where T: Add<Output = T> + Mul<Output = T> + Div<Output = T> + Copy {
         normal * Self::dot(vector, normal) / normal.sqr_magnitude()
     }
+
+    #[inline]
+    pub fn lerp(a: Self, b: Self, t: T) -> Self
+    where T: Real {
+        Self { x: interpolate(a.x, b.x, t), y: interpolate(a.y, b.y, t), z: interpolate(a.z, b.z, t), w: interpolate(a.w, b.w, t) }
+    }
+
+    #[inline]
+    pub fn lerp_clamped(a: Self, b: Self, t: T) -> Self
+    where T: Real {
+        Self::lerp(a, b, t.max(T::zero()).min(T::one()))
+    }
+
+    #[inline]
+    pub fn min(a: Self, b: Self) -> Self
+    where T: PartialOrd + Copy {
+        Self { x: if a.x < b.x { a.x } else { b.x }, y: if a.y < b.y { a.y } else { b.y }, z: if a.z < b.z { a.z } else { b.z }, w: if a.w < b.w { a.w } else { b.w } }
+    }
+
+    #[inline]
+    pub fn max(a: Self, b: Self) -> Self
+    where T: PartialOrd + Copy {
+        Self { x: if a.x > b.x { a.x } else { b.x }, y: if a.y > b.y { a.y } else { b.y }, z: if a.z > b.z { a.z } else { b.z }, w: if a.w > b.w { a.w } else { b.w } }
+    }
+
+    #[inline]
+    pub fn abs(self) -> Self
+    where T: Real {
+        Self { x: self.x.abs(), y: self.y.abs(), z: self.z.abs(), w: self.w.abs() }
+    }
+
+    #[inline]
+    pub fn floor(self) -> Self
+    where T: Real {
+        Self { x: self.x.floor(), y: self.y.floor(), z: self.z.floor(), w: self.w.floor() }
+    }
+
+    #[inline]
+    pub fn ceil(self) -> Self
+    where T: Real {
+        Self { x: self.x.ceil(), y: self.y.ceil(), z: self.z.ceil(), w: self.w.ceil() }
+    }
+
+    #[inline]
+    pub fn round(self) -> Self
+    where T: Real {
+        Self { x: self.x.round(), y: self.y.round(), z: self.z.round(), w: self.w.round() }
+    }
+
+    #[inline]
+    pub fn clamp_magnitude(self, max: T) -> Self
+    where T: Real {
+        let sqr_magnitude = self.sqr_magnitude();
+
+        if sqr_magnitude > max * max {
+            self * (max / sqr_magnitude.sqrt())
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    pub fn reject(vector: Self, normal: Self) -> Self
+    where T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Copy {
+        vector - Self::project(vector, normal)
+    }
+
+    #[inline]
+    pub fn approx_eq(self, other: Self, epsilon: T) -> bool
+    where T: Real {
+        (self.x - other.x).abs() <= epsilon && (self.y - other.y).abs() <= epsilon && (self.z - other.z).abs() <= epsilon && (self.w - other.w).abs() <= epsilon
+    }
+
+    #[inline]
+    pub fn try_normalized(&self) -> Option<Self>
+    where T: Real {
+        let magnitude = self.magnitude();
+
+        if magnitude > T::zero() {
+            Some(*self / magnitude)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn normalized_or_zero(&self) -> Self
+    where T: Real {
+        self.try_normalized().unwrap_or(Self { x: T::zero(), y: T::zero(), z: T::zero(), w: T::zero() })
+    }
+
+    #[inline]
+    pub fn truncate(self) -> Vector3<T> {
+        Vector3::new_comp(self.x, self.y, self.z)
+    }
+
+    #[inline]
+    pub fn xy(self) -> Vector2<T>
+    where T: Copy {
+        Vector2::new_comp(self.x, self.y)
+    }
+
+    #[inline]
+    pub fn xz(self) -> Vector2<T>
+    where T: Copy {
+        Vector2::new_comp(self.x, self.z)
+    }
+
+    #[inline]
+    pub fn yz(self) -> Vector2<T>
+    where T: Copy {
+        Vector2::new_comp(self.y, self.z)
+    }
+
+    #[inline]
+    pub fn zw(self) -> Vector2<T>
+    where T: Copy {
+        Vector2::new_comp(self.z, self.w)
+    }
+
+    #[inline]
+    pub fn xyz(self) -> Vector3<T>
+    where T: Copy {
+        Vector3::new_comp(self.x, self.y, self.z)
+    }
+
+    #[inline]
+    pub fn xyw(self) -> Vector3<T>
+    where T: Copy {
+        Vector3::new_comp(self.x, self.y, self.w)
+    }
+
+    #[inline]
+    pub fn yzw(self) -> Vector3<T>
+    where T: Copy {
+        Vector3::new_comp(self.y, self.z, self.w)
+    }
+
+    #[inline]
+    pub fn wzyx(self) -> Vector4<T>
+    where T: Copy {
+        Vector4::new_comp(self.w, self.z, self.y, self.x)
+    }
+
+    /// Clamps each component into `[min, max]`. Where `min` exceeds `max` on an axis, that component is set to `max`.
+    #[inline]
+    pub fn clamp(self, min: Self, max: Self) -> Self
+    where T: PartialOrd + Copy {
+        Self::min(Self::max(self, min), max)
+    }
+
+    #[inline]
+    pub fn angle(from: Self, to: Self) -> T
+    where T: Real {
+        let denominator = (from.sqr_magnitude() * to.sqr_magnitude()).sqrt();
+
+        if denominator == T::zero() {
+            return T::zero();
+        }
+
+        let cos = Self::dot(from, to) / denominator;
+        cos.max(-T::one()).min(T::one()).acos()
+    }
+
+    #[inline]
+    pub fn angle_degrees(from: Self, to: Self) -> T
+    where T: Real {
+        Self::angle(from, to).to_degrees()
+    }
+
+    #[inline]
+    pub fn sum<I: IntoIterator<Item = Self>>(iter: I) -> Self
+    where T: Zero {
+        iter.into_iter().sum()
+    }
+
+    /// Component-wise mean of the vectors, or `None` when `iter` is empty.
+    #[inline]
+    pub fn average<I: IntoIterator<Item = Self>>(iter: I) -> Option<Self>
+    where T: Real {
+        let mut count = 0usize;
+        let sum = Self::sum(iter.into_iter().inspect(|_| count += 1));
+
+        if count == 0 {
+            return None;
+        }
+
+        Some(sum / T::from(count)?)
+    }
+
+    /// Converts each component with [`NumCast`], returning `None` when one of them
+    /// cannot be represented in `U`. Narrowing between float types rounds like `as`,
+    /// so out-of-range values become infinite instead.
+    #[inline]
+    pub fn cast<U>(self) -> Option<Vector4<U>>
+    where T: NumCast, U: NumCast {
+        Some(Vector4 { x: U::from(self.x)?, y: U::from(self.y)?, z: U::from(self.z)?, w: U::from(self.w)? })
+    }
+
+    /// Index of the largest component. Ties go to the lowest index.
+    #[inline]
+    pub fn max_axis(self) -> usize
+    where T: PartialOrd + Copy {
+        let components = [self.x, self.y, self.z, self.w];
+        (1..components.len()).fold(0, |axis, i| if components[i] > components[axis] { i } else { axis })
+    }
+
+    /// Index of the smallest component. Ties go to the lowest index.
+    #[inline]
+    pub fn min_axis(self) -> usize
+    where T: PartialOrd + Copy {
+        let components = [self.x, self.y, self.z, self.w];
+        (1..components.len()).fold(0, |axis, i| if components[i] < components[axis] { i } else { axis })
+    }
+
+    #[inline]
+    pub fn max_component(self) -> T
+    where T: PartialOrd + Copy {
+        [self.x, self.y, self.z, self.w][self.max_axis()]
+    }
+
+    #[inline]
+    pub fn min_component(self) -> T
+    where T: PartialOrd + Copy {
+        [self.x, self.y, self.z, self.w][self.min_axis()]
+    }
+
+    /// Rounds each component to the nearest multiple of the matching `cell` component.
+    /// Components with a cell size of zero are left unchanged.
+    #[inline]
+    pub fn snap_to_grid(self, cell: Self) -> Self
+    where T: Real {
+        Self { x: snap(self.x, cell.x, T::round), y: snap(self.y, cell.y, T::round), z: snap(self.z, cell.z, T::round), w: snap(self.w, cell.w, T::round) }
+    }
+
+    #[inline]
+    pub fn snap_to_grid_uniform(self, cell: T) -> Self
+    where T: Real {
+        self.snap_to_grid(Self::new_comp(cell, cell, cell, cell))
+    }
+
+    /// Like [`Self::snap_to_grid`], but rounds down to the cell containing each component.
+    #[inline]
+    pub fn snap_floor(self, cell: Self) -> Self
+    where T: Real {
+        Self { x: snap(self.x, cell.x, T::floor), y: snap(self.y, cell.y, T::floor), z: snap(self.z, cell.z, T::floor), w: snap(self.w, cell.w, T::floor) }
+    }
+
+    /// Homogeneous point with `w = 1`, so translations apply to it.
+    #[inline]
+    pub fn from_point(point: Vector3<T>) -> Self
+    where T: One {
+        Self { x: point.x, y: point.y, z: point.z, w: T::one() }
+    }
+
+    /// Homogeneous direction with `w = 0`, so translations leave it unchanged.
+    #[inline]
+    pub fn from_direction(direction: Vector3<T>) -> Self
+    where T: Zero {
+        Self { x: direction.x, y: direction.y, z: direction.z, w: T::zero() }
+    }
+
+    /// Divides `x`, `y` and `z` by `w`, or returns `None` when `w` is zero.
+    #[inline]
+    pub fn perspective_divide(self) -> Option<Vector3<T>>
+    where T: Real {
+        if self.w == T::zero() {
+            return None;
+        }
+
+        Some(Vector3::new_comp(self.x / self.w, self.y / self.w, self.z / self.w))
+    }
+
+    /// Whether every component is neither infinite nor NaN.
+    #[inline]
+    pub fn is_finite(&self) -> bool
+    where T: Float {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite() && self.w.is_finite()
+    }
+
+    #[inline]
+    pub fn has_nan(&self) -> bool
+    where T: Float {
+        self.x.is_nan() || self.y.is_nan() || self.z.is_nan() || self.w.is_nan()
+    }
+
+    #[inline]
+    pub fn map<U>(self, f: impl Fn(T) -> U) -> Vector4<U> {
+        Vector4 { x: f(self.x), y: f(self.y), z: f(self.z), w: f(self.w) }
+    }
+
+    /// Applies `f` to each pair of matching components of `self` and `other`.
+    #[inline]
+    pub fn zip_map<U, V>(self, other: Vector4<U>, f: impl Fn(T, U) -> V) -> Vector4<V> {
+        Vector4 { x: f(self.x, other.x), y: f(self.y, other.y), z: f(self.z, other.z), w: f(self.w, other.w) }
+    }
+
+    /// True when `sqrt(|self|² * |other|² - dot(self, other)²)` is within `epsilon` of zero. This
+    /// equals the cross product magnitude used by [`Vector3::is_parallel`], as 4D has no cross product.
+    #[inline]
+    pub fn is_parallel(&self, other: &Self, epsilon: T) -> bool
+    where T: Real {
+        let dot = Self::dot(*self, *other);
+        (self.sqr_magnitude() * other.sqr_magnitude() - dot * dot).max(T::zero()).sqrt() <= epsilon
+    }
+
+    /// True when the dot product of `self` and `other` is within `epsilon` of zero.
+    #[inline]
+    pub fn is_perpendicular(&self, other: &Self, epsilon: T) -> bool
+    where T: Real {
+        Self::dot(*self, *other).abs() <= epsilon
+    }
 }
 
 impl<T> Vector for Vector4<T>
@@ -1398,6 +2705,7 @@ impl<T> Index<usize> for Vector4<T> {
             0 => &self.x,
             1 => &self.y,
             2 => &self.z,
+            3 => &self.w,
             _ => panic!("Index out of bounds")
         }
     }
@@ -1410,6 +2718,7 @@ impl<T> IndexMut<usize> for Vector4<T> {
             0 => &mut self.x,
             1 => &mut self.y,
             2 => &mut self.z,
+            3 => &mut self.w,
             _ => panic!("Index out of bounds")
         }
     }
@@ -1496,12 +2805,12 @@ where T: Mul<Output = T> {
 }
 
 impl<'a, T> Mul<&'a Vector4<T>> for Vector4<T>
-where T: Mul<Output = T> + Deref<Target = T> + Copy {
+where T: Mul<Output = T> + Copy {
     type Output = Self;
 
     #[inline]
     fn mul(self, rhs: &'a Vector4<T>) -> Self::Output {
-        Self { x: self.x * *rhs.x, y: self.y * *rhs.y, z: self.z * *rhs.z, w: self.w * *rhs.w }
+        Self { x: self.x * rhs.x, y: self.y * rhs.y, z: self.z * rhs.z, w: self.w * rhs.w }
     }
 }
 
@@ -1536,12 +2845,12 @@ where T: Div<Output = T> {
 }
 
 impl<'a, T> Div<&'a Vector4<T>> for Vector4<T>
-where T: Div<Output = T> + Deref<Target = T> + Copy {
+where T: Div<Output = T> + Copy {
     type Output = Self;
 
     #[inline]
     fn div(self, rhs: &'a Vector4<T>) -> Self::Output {
-        Self { x: self.x / *rhs.x, y: self.y / *rhs.y, z: self.z / *rhs.z, w: self.w / *rhs.w }
+        Self { x: self.x / rhs.x, y: self.y / rhs.y, z: self.z / rhs.z, w: self.w / rhs.w }
     }
 }
 
@@ -1551,6 +2860,8 @@ where T: AddAssign {
     fn add_assign(&mut self, rhs: Self) {
         self.x += rhs.x;
         self.y += rhs.y;
+        self.z += rhs.z;
+        self.w += rhs.w;
     }
 }
 
@@ -1560,6 +2871,8 @@ where T: AddAssign + Copy {
     fn add_assign(&mut self, rhs: &'a Vector4<T>) {
         self.x += rhs.x;
         self.y += rhs.y;
+        self.z += rhs.z;
+        self.w += rhs.w;
     }
 }
 
@@ -1569,6 +2882,8 @@ where T: SubAssign {
     fn sub_assign(&mut self, rhs: Self) {
         self.x -= rhs.x;
         self.y -= rhs.y;
+        self.z -= rhs.z;
+        self.w -= rhs.w;
     }
 }
 
@@ -1578,6 +2893,8 @@ where T: SubAssign + Copy {
     fn sub_assign(&mut self, rhs: &'a Vector4<T>) {
         self.x -= rhs.x;
         self.y -= rhs.y;
+        self.z -= rhs.z;
+        self.w -= rhs.w;
     }
 }
 
@@ -1587,6 +2904,8 @@ where T: MulAssign + Copy {
     fn mul_assign(&mut self, rhs: T) {
         self.x *= rhs;
         self.y *= rhs;
+        self.z *= rhs;
+        self.w *= rhs;
     }
 }
 
@@ -1596,6 +2915,8 @@ where T: MulAssign + Copy {
     fn mul_assign(&mut self, rhs: &'a T) {
         self.x *= *rhs;
         self.y *= *rhs;
+        self.z *= *rhs;
+        self.w *= *rhs;
     }
 }
 
@@ -1605,15 +2926,19 @@ where T: MulAssign {
     fn mul_assign(&mut self, rhs: Vector4<T>) {
         self.x *= rhs.x;
         self.y *= rhs.y;
+        self.z *= rhs.z;
+        self.w *= rhs.w;
     }
 }
 
 impl<'a, T> MulAssign<&'a Vector4<T>> for Vector4<T>
-where T: MulAssign + Deref<Target = T> + Copy {
+where T: MulAssign + Copy {
     #[inline]
     fn mul_assign(&mut self, rhs: &'a Vector4<T>) {
-        self.x *= *rhs.x;
-        self.y *= *rhs.y;
+        self.x *= rhs.x;
+        self.y *= rhs.y;
+        self.z *= rhs.z;
+        self.w *= rhs.w;
     }
 }
 
@@ -1623,6 +2948,8 @@ where T: DivAssign + Copy {
     fn div_assign(&mut self, rhs: T) {
         self.x /= rhs;
         self.y /= rhs;
+        self.z /= rhs;
+        self.w /= rhs;
     }
 }
 
@@ -1632,6 +2959,8 @@ where T: DivAssign + Copy {
     fn div_assign(&mut self, rhs: &'a T) {
         self.x /= *rhs;
         self.y /= *rhs;
+        self.z /= *rhs;
+        self.w /= *rhs;
     }
 }
 
@@ -1641,15 +2970,19 @@ where T: DivAssign {
     fn div_assign(&mut self, rhs: Vector4<T>) {
         self.x /= rhs.x;
         self.y /= rhs.y;
+        self.z /= rhs.z;
+        self.w /= rhs.w;
     }
 }
 
 impl<'a, T> DivAssign<&'a Vector4<T>> for Vector4<T>
-where T: DivAssign + Deref<Target = T> + Copy {
+where T: DivAssign + Copy {
     #[inline]
     fn div_assign(&mut self, rhs: &'a Vector4<T>) {
-        self.x /= *rhs.x;
-        self.y /= *rhs.y;
+        self.x /= rhs.x;
+        self.y /= rhs.y;
+        self.z /= rhs.z;
+        self.w /= rhs.w;
     }
 }
 
@@ -1668,11 +3001,23 @@ where T: Copy {
     }
 }
 
+impl<T> TryFrom<&[T]> for Vector4<T>
+where T: Copy {
+    type Error = LengthError;
+
+    #[inline]
+    fn try_from(slice: &[T]) -> Result<Self, Self::Error> {
+        <[T; 4]>::try_from(slice)
+            .map(Self::from)
+            .map_err(|_| LengthError { expected: 4, actual: slice.len() })
+    }
+}
+
 impl<T> From<[[T; 1]; 4]> for Vector4<T>
 where T: Copy {
     #[inline]
     fn from(array: [[T; 1]; 4]) -> Self {
-        Self { x: array[0][0], y: array[0][1], z: array[0][2], w: array[0][3] }
+        Self { x: array[0][0], y: array[1][0], z: array[2][0], w: array[3][0] }
     }
 }
 
@@ -1715,14 +3060,60 @@ where T: Real {
     }
 }
 
+#[cfg(feature = "mint")]
+impl<T> From<mint::Vector4<T>> for Vector4<T> {
+    #[inline]
+    fn from(value: mint::Vector4<T>) -> Self {
+        Self { x: value.x, y: value.y, z: value.z, w: value.w }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<T> From<Vector4<T>> for mint::Vector4<T> {
+    #[inline]
+    fn from(vector: Vector4<T>) -> Self {
+        Self { x: vector.x, y: vector.y, z: vector.z, w: vector.w }
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> approx::AbsDiffEq for Vector4<T>
+where T: approx::AbsDiffEq, T::Epsilon: Copy {
+    type Epsilon = T::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        T::abs_diff_eq(&self.x, &other.x, epsilon) && T::abs_diff_eq(&self.y, &other.y, epsilon) && T::abs_diff_eq(&self.z, &other.z, epsilon) && T::abs_diff_eq(&self.w, &other.w, epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> approx::RelativeEq for Vector4<T>
+where T: approx::RelativeEq, T::Epsilon: Copy {
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+        T::relative_eq(&self.x, &other.x, epsilon, max_relative) && T::relative_eq(&self.y, &other.y, epsilon, max_relative) && T::relative_eq(&self.z, &other.z, epsilon, max_relative) && T::relative_eq(&self.w, &other.w, epsilon, max_relative)
+    }
+}
+
 impl<T> IntoIterator for Vector4<T> {
     type Item = T;
 
-    type IntoIter = std::array::IntoIter<Self::Item, 2>;
+    type IntoIter = std::array::IntoIter<Self::Item, 4>;
 
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
-        [self.x, self.y].into_iter()
+        [self.x, self.y, self.z, self.w].into_iter()
     }
 }
 
@@ -1752,6 +3143,31 @@ where T: Real {
     }
 }
 
+impl<T> Sum for Vector4<T>
+where T: Zero {
+    #[inline]
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self { x: T::zero(), y: T::zero(), z: T::zero(), w: T::zero() }, |acc, vector| acc + vector)
+    }
+}
+
+impl<T> Product for Vector4<T>
+where T: One {
+    #[inline]
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self { x: T::one(), y: T::one(), z: T::one(), w: T::one() }, |acc, vector| acc * vector)
+    }
+}
+
+fn snap<T>(value: T, cell: T, round: fn(T) -> T) -> T
+where T: Real {
+    if cell == T::zero() {
+        return value;
+    }
+
+    round(value / cell) * cell
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1767,4 +3183,741 @@ mod tests {
         assert_eq!(vector.x, 5);
         assert_eq!(vector.y, 5);
     }
+
+    #[test]
+    fn vector2_bounce_elastic() {
+        let velocity = Vector2::new_comp(1.0, -1.0);
+        let bounced = Vector2::bounce(velocity, Vector2::up(), 1.0);
+        assert_eq!(bounced, Vector2::new_comp(1.0, 1.0));
+    }
+
+    #[test]
+    fn vector2_bounce_inelastic() {
+        let velocity = Vector2::new_comp(2.0, -3.0);
+        let bounced = Vector2::bounce(velocity, Vector2::up(), 0.0);
+        assert_eq!(bounced, Vector2::new_comp(2.0, 0.0));
+    }
+
+    #[test]
+    fn vector3_assign_ops() {
+        let mut vector = Vector3::new_comp(1, 2, 3);
+        vector += Vector3::new_comp(1, 1, 1);
+        assert_eq!(vector, Vector3::new_comp(2, 3, 4));
+
+        vector -= &Vector3::new_comp(1, 2, 3);
+        assert_eq!(vector, Vector3::new_comp(1, 1, 1));
+
+        vector *= 3;
+        assert_eq!(vector, Vector3::new_comp(3, 3, 3));
+
+        vector /= Vector3::new_comp(1, 3, 3);
+        assert_eq!(vector, Vector3::new_comp(3, 1, 1));
+    }
+
+    #[test]
+    fn vector4_assign_ops() {
+        let mut vector = Vector4::new_comp(1.0, 2.0, 3.0, 4.0);
+        vector += Vector4::new_comp(1.0, 1.0, 1.0, 1.0);
+        assert_eq!(vector, Vector4::new_comp(2.0, 3.0, 4.0, 5.0));
+
+        vector -= Vector4::new_comp(2.0, 3.0, 4.0, 5.0);
+        assert_eq!(vector, Vector4::new_comp(0.0, 0.0, 0.0, 0.0));
+
+        vector += &Vector4::new_comp(2.0, 4.0, 6.0, 8.0);
+        vector *= Vector4::new_comp(1.0, 1.0, 1.0, 2.0);
+        assert_eq!(vector, Vector4::new_comp(2.0, 4.0, 6.0, 16.0));
+
+        vector /= &2.0;
+        assert_eq!(vector, Vector4::new_comp(1.0, 2.0, 3.0, 8.0));
+    }
+
+    #[test]
+    fn vector_into_iter() {
+        assert_eq!(Vector3::new_comp(1, 2, 3).into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(Vector4::new_comp(1, 2, 3, 4).into_iter().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+
+        let vector = Vector4::new_comp(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(vector.into_iter().collect::<Vector4<f64>>(), vector);
+    }
+
+    #[test]
+    fn vector4_index() {
+        let mut vector = Vector4::new_comp(1, 2, 3, 4);
+        assert_eq!(vector[3], 4);
+
+        vector[3] = 7;
+        assert_eq!(vector.w, 7);
+
+        let sum: i32 = (0..4).map(|i| vector[i]).sum();
+        assert_eq!(sum, 13);
+    }
+
+    #[test]
+    #[should_panic]
+    fn vector4_index_out_of_bounds() {
+        let vector = Vector4::new_comp(1, 2, 3, 4);
+        let _ = vector[4];
+    }
+
+    #[test]
+    fn vector_lerp() {
+        let a = Vector2::new_comp(0.0, 10.0);
+        let b = Vector2::new_comp(10.0, 20.0);
+        assert_eq!(Vector2::lerp(a, b, 0.0), a);
+        assert_eq!(Vector2::lerp(a, b, 0.5), Vector2::new_comp(5.0, 15.0));
+        assert_eq!(Vector2::lerp(a, b, 1.0), b);
+        assert_eq!(Vector2::lerp(a, b, 2.0), Vector2::new_comp(20.0, 30.0));
+
+        let a = Vector3::new_comp(0.0, 0.0, 0.0);
+        let b = Vector3::new_comp(2.0, 4.0, 8.0);
+        assert_eq!(Vector3::lerp(a, b, 0.5), Vector3::new_comp(1.0, 2.0, 4.0));
+
+        let a = Vector4::new_comp(0.0, 0.0, 0.0, 0.0);
+        let b = Vector4::new_comp(2.0, 4.0, 8.0, 16.0);
+        assert_eq!(Vector4::lerp(a, b, 0.25), Vector4::new_comp(0.5, 1.0, 2.0, 4.0));
+    }
+
+    #[test]
+    fn vector_lerp_clamped() {
+        let a = Vector2::new_comp(0.0, 10.0);
+        let b = Vector2::new_comp(10.0, 20.0);
+        assert_eq!(Vector2::lerp_clamped(a, b, 0.5), Vector2::new_comp(5.0, 15.0));
+        assert_eq!(Vector2::lerp_clamped(a, b, -1.0), a);
+        assert_eq!(Vector2::lerp_clamped(a, b, 2.0), b);
+
+        let a = Vector3::new_comp(0.0, 0.0, 0.0);
+        let b = Vector3::new_comp(1.0, 1.0, 1.0);
+        assert_eq!(Vector3::lerp_clamped(a, b, 1.5), b);
+        assert_eq!(Vector4::lerp_clamped(Vector4::from(a), Vector4::from(b), -0.5), Vector4::from(a));
+    }
+
+    #[test]
+    fn vector2_rotate() {
+        use std::f64::consts::PI;
+
+        let rotated = Vector2::right().rotate(PI / 2.0);
+        assert!(Vector2::distance(rotated, Vector2::up()) < 1e-12);
+
+        let v = Vector2::new_comp(3.0, -2.0);
+        assert!(Vector2::distance(v.rotate(2.0 * PI), v) < 1e-12);
+    }
+
+    #[test]
+    fn vector2_rotate_around() {
+        use std::f64::consts::PI;
+
+        let v = Vector2::new_comp(3.0, -2.0);
+        assert_eq!(v.rotate_around(v, 1.234), v);
+
+        let rotated = Vector2::new_comp(2.0, 1.0).rotate_around(Vector2::new_comp(1.0, 1.0), PI);
+        assert!(Vector2::distance(rotated, Vector2::new_comp(0.0, 1.0)) < 1e-12);
+    }
+
+    #[test]
+    fn vector_min_max() {
+        let a = Vector2::new_comp(-1, 5);
+        let b = Vector2::new_comp(3, -2);
+        assert_eq!(Vector2::min(a, b), Vector2::new_comp(-1, -2));
+        assert_eq!(Vector2::max(a, b), Vector2::new_comp(3, 5));
+
+        let a = Vector3::new_comp(-1.5, 2.0, 0.0);
+        let b = Vector3::new_comp(1.5, -2.0, -0.5);
+        assert_eq!(Vector3::min(a, b), Vector3::new_comp(-1.5, -2.0, -0.5));
+        assert_eq!(Vector3::max(a, b), Vector3::new_comp(1.5, 2.0, 0.0));
+
+        let a = Vector4::new_comp(1, -1, 1, -1);
+        let b = Vector4::new_comp(-1, 1, -1, 1);
+        assert_eq!(Vector4::min(a, b), Vector4::new_comp(-1, -1, -1, -1));
+        assert_eq!(Vector4::max(a, b), Vector4::new_comp(1, 1, 1, 1));
+    }
+
+    #[test]
+    fn vector_rounding() {
+        let v = Vector2::new_comp(-1.5, 2.25);
+        assert_eq!(v.abs(), Vector2::new_comp(1.5, 2.25));
+        assert_eq!(v.floor(), Vector2::new_comp(-2.0, 2.0));
+        assert_eq!(v.ceil(), Vector2::new_comp(-1.0, 3.0));
+        assert_eq!(v.round(), Vector2::new_comp(-2.0, 2.0));
+
+        let v = Vector3::new_comp(-0.4, 0.6, -2.7);
+        assert_eq!(v.abs(), Vector3::new_comp(0.4, 0.6, 2.7));
+        assert_eq!(v.floor(), Vector3::new_comp(-1.0, 0.0, -3.0));
+        assert_eq!(v.ceil(), Vector3::new_comp(-0.0, 1.0, -2.0));
+        assert_eq!(v.round(), Vector3::new_comp(-0.0, 1.0, -3.0));
+
+        let v = Vector4::new_comp(1.5, -1.5, 0.1, -0.1);
+        assert_eq!(v.abs(), Vector4::new_comp(1.5, 1.5, 0.1, 0.1));
+        assert_eq!(v.floor(), Vector4::new_comp(1.0, -2.0, 0.0, -1.0));
+        assert_eq!(v.ceil(), Vector4::new_comp(2.0, -1.0, 1.0, -0.0));
+        assert_eq!(v.round(), Vector4::new_comp(2.0, -2.0, 0.0, -0.0));
+    }
+
+    #[test]
+    fn vector_clamp_magnitude() {
+        let short = Vector2::new_comp(0.3, 0.4);
+        assert_eq!(short.clamp_magnitude(1.0), short);
+        assert_eq!(Vector2::new_comp(0.0, 0.0).clamp_magnitude(1.0), Vector2::new_comp(0.0, 0.0));
+
+        let long = Vector2::new_comp(6.0, 8.0).clamp_magnitude(5.0);
+        assert_eq!(long, Vector2::new_comp(3.0, 4.0));
+
+        let long = Vector3::new_comp(0.0, 0.0, -10.0).clamp_magnitude(2.0);
+        assert_eq!(long, Vector3::new_comp(0.0, 0.0, -2.0));
+
+        let long = Vector4::new_comp(2.0, 2.0, 2.0, 2.0).clamp_magnitude(2.0);
+        assert!(f64::abs(long.magnitude() - 2.0) < 1e-12);
+        assert_eq!(long, Vector4::new_comp(1.0, 1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn vector_project_and_reject() {
+        let v = Vector2::new_comp(3.0, 4.0);
+        assert_eq!(Vector2::project(v, Vector2::new_comp(2.0, 0.0)), Vector2::new_comp(3.0, 0.0));
+        assert_eq!(Vector2::reject(v, Vector2::new_comp(2.0, 0.0)), Vector2::new_comp(0.0, 4.0));
+
+        let v = Vector3::new_comp(1.0, 2.0, 3.0);
+        assert_eq!(Vector3::project(v, Vector3::up()), Vector3::new_comp(0.0, 2.0, 0.0));
+        assert_eq!(Vector3::reject(v, Vector3::up()), Vector3::new_comp(1.0, 0.0, 3.0));
+
+        let v = Vector4::new_comp(1.0, 2.0, 3.0, 4.0);
+        let w = Vector4::new_comp(0.0, 0.0, 0.0, 5.0);
+        assert_eq!(Vector4::project(v, w), Vector4::new_comp(0.0, 0.0, 0.0, 4.0));
+        assert_eq!(Vector4::reject(v, w), Vector4::new_comp(1.0, 2.0, 3.0, 0.0));
+    }
+
+    #[test]
+    fn vector_reflect() {
+        let reflected = Vector2::reflect(Vector2::new_comp(1.0, -1.0), Vector2::new_comp(0.0, 1.0));
+        assert_eq!(reflected, Vector2::new_comp(1.0, 1.0));
+
+        let reflected = Vector3::reflect(Vector3::new_comp(1.0, -2.0, 3.0), Vector3::up());
+        assert_eq!(reflected, Vector3::new_comp(1.0, 2.0, 3.0));
+
+        let reflected = Vector4::reflect(Vector4::new_comp(1.0, 2.0, 3.0, -4.0), Vector4::new_comp(0.0, 0.0, 0.0, 1.0));
+        assert_eq!(reflected, Vector4::new_comp(1.0, 2.0, 3.0, 4.0));
+    }
+
+    #[test]
+    fn vector_reflect_unnormalized() {
+        let direction = Vector2::new_comp(1.0, -1.0);
+        let normal = Vector2::new_comp(0.0, 2.0);
+        assert_eq!(Vector2::reflect_unnormalized(direction, normal), Vector2::new_comp(1.0, 1.0));
+        assert_eq!(Vector2::reflect_unnormalized(direction, normal), Vector2::reflect(direction, normal.normalized()));
+        assert_eq!(Vector2::reflect(direction, normal), Vector2::new_comp(1.0, 7.0));
+
+        let direction = Vector3::new_comp(1.0, -2.0, 3.0);
+        let normal = Vector3::new_comp(2.0, 0.0, 0.0);
+        assert_eq!(Vector3::reflect_unnormalized(direction, normal), Vector3::reflect(direction, Vector3::right()));
+
+        let direction = Vector4::new_comp(1.0, 2.0, 3.0, -4.0);
+        let normal = Vector4::new_comp(0.0, 0.0, 0.0, 2.0);
+        assert_eq!(Vector4::reflect_unnormalized(direction, normal), Vector4::new_comp(1.0, 2.0, 3.0, 4.0));
+    }
+
+    #[test]
+    fn vector_catmull_rom() {
+        let p0 = Vector2::new_comp(-1.0, 0.0);
+        let p1 = Vector2::new_comp(0.0, 0.0);
+        let p2 = Vector2::new_comp(1.0, 1.0);
+        let p3 = Vector2::new_comp(2.0, 1.0);
+        assert_eq!(Vector2::catmull_rom(p0, p1, p2, p3, 0.0), p1);
+        assert_eq!(Vector2::catmull_rom(p0, p1, p2, p3, 1.0), p2);
+
+        let mid = Vector2::catmull_rom(p0, p1, p2, p3, 0.5);
+        assert!(mid.x > p1.x && mid.x < p2.x);
+        assert!(mid.y > p1.y && mid.y < p2.y);
+
+        let p0 = Vector3::new_comp(0.0, 0.0, 0.0);
+        let p1 = Vector3::new_comp(1.0, 0.0, 0.0);
+        let p2 = Vector3::new_comp(2.0, 0.0, 0.0);
+        let p3 = Vector3::new_comp(3.0, 0.0, 0.0);
+        assert_eq!(Vector3::catmull_rom(p0, p1, p2, p3, 0.0), p1);
+        assert_eq!(Vector3::catmull_rom(p0, p1, p2, p3, 1.0), p2);
+        assert_eq!(Vector3::catmull_rom(p0, p1, p2, p3, 0.5), Vector3::new_comp(1.5, 0.0, 0.0));
+    }
+
+    #[test]
+    fn vector_bezier() {
+        let a = Vector2::new_comp(0.1, 0.3);
+        let b = Vector2::new_comp(0.7, 2.9);
+        let c = Vector2::new_comp(3.3, -1.7);
+        let d = Vector2::new_comp(4.1, 0.2);
+
+        assert_eq!(Vector2::bezier3(a, b, c, 0.0), a);
+        assert_eq!(Vector2::bezier3(a, b, c, 1.0), c);
+        assert_eq!(Vector2::bezier4(a, b, c, d, 0.0), a);
+        assert_eq!(Vector2::bezier4(a, b, c, d, 1.0), d);
+
+        for t in [0.1, 0.25, 0.5, 0.8] {
+            let u = 1.0 - t;
+            let expected = a * (u * u * u) + b * (3.0 * u * u * t) + c * (3.0 * u * t * t) + d * (t * t * t);
+            assert!(Vector2::distance(Vector2::bezier4(a, b, c, d, t), expected) < 1e-12);
+
+            let expected = a * (u * u) + b * (2.0 * u * t) + c * (t * t);
+            assert!(Vector2::distance(Vector2::bezier3(a, b, c, t), expected) < 1e-12);
+        }
+
+        let a = Vector3::new_comp(0.0, 0.0, 0.0);
+        let b = Vector3::new_comp(1.0, 2.0, 0.0);
+        let c = Vector3::new_comp(2.0, 2.0, 1.0);
+        let d = Vector3::new_comp(3.0, 0.0, 1.0);
+        assert_eq!(Vector3::bezier4(a, b, c, d, 1.0), d);
+        assert_eq!(Vector3::bezier4(a, b, c, d, 0.5), Vector3::new_comp(1.5, 1.5, 0.5));
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn vector_cast_slice() {
+        let vectors = [Vector3::new_comp(1.0f32, 2.0, 3.0), Vector3::new_comp(4.0, 5.0, 6.0)];
+        let floats: &[f32] = bytemuck::cast_slice(&vectors);
+        assert_eq!(floats, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let vector: Vector4<f64> = bytemuck::cast([1.0f64, 2.0, 3.0, 4.0]);
+        assert_eq!(vector, Vector4::new_comp(1.0, 2.0, 3.0, 4.0));
+        assert_eq!(bytemuck::bytes_of(&Vector2::new_comp(0.0f32, 0.0)), &[0u8; 8]);
+    }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn vector_mint_round_trip() {
+        let vector = Vector3::new_comp(1.0f32, 2.0, 3.0);
+        let mint_vector: mint::Vector3<f32> = vector.into();
+        assert_eq!((mint_vector.x, mint_vector.y, mint_vector.z), (1.0, 2.0, 3.0));
+        assert_eq!(Vector3::from(mint_vector), vector);
+
+        let mint_point: mint::Point2<i32> = Vector2::new_comp(4, 5).into();
+        assert_eq!(Vector2::from(mint_point), Vector2::new_comp(4, 5));
+
+        let mint_vector = mint::Vector4 { x: 1.0, y: 2.0, z: 3.0, w: 4.0 };
+        assert_eq!(Vector4::from(mint_vector), Vector4::new_comp(1.0, 2.0, 3.0, 4.0));
+    }
+
+    #[test]
+    fn vector_approx_eq() {
+        let a = Vector3::new_comp(1.0, 2.0, 3.0).normalized();
+        let b = Vector3::new_comp(1.0000001, 2.0, 3.0).normalized();
+        assert_ne!(a, b);
+        assert!(a.approx_eq(b, 1e-6));
+        assert!(!a.approx_eq(b, 1e-9));
+
+        let rotated = Vector2::right().rotate(std::f64::consts::PI / 2.0);
+        assert_ne!(rotated, Vector2::up());
+        assert!(rotated.approx_eq(Vector2::up(), 1e-6));
+
+        let a = Vector4::new_comp(0.1 + 0.2, 1.0, 1.0, 1.0);
+        assert!(a.approx_eq(Vector4::new_comp(0.3, 1.0, 1.0, 1.0), 1e-12));
+        assert!(!a.approx_eq(Vector4::new_comp(0.3, 1.0, 1.0, 1.1), 1e-6));
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn vector_approx_crate() {
+        let a = Vector3::new_comp(1.0, 2.0, 3.0).normalized();
+        let b = Vector3::new_comp(1.0000001, 2.0, 3.0).normalized();
+        approx::assert_abs_diff_eq!(a, b, epsilon = 1e-6);
+        approx::assert_relative_eq!(Vector2::new_comp(0.1 + 0.2, 1.0), Vector2::new_comp(0.3, 1.0));
+        approx::assert_relative_ne!(Vector4::new_comp(1.0, 1.0, 1.0, 1.0), Vector4::new_comp(1.0, 1.0, 1.0, 1.5));
+    }
+
+    #[test]
+    fn vector3_orthonormal_basis() {
+        let inputs = [
+            Vector3::new_comp(1.0, 0.0, 0.0),
+            Vector3::new_comp(0.0, 1.0, 0.0),
+            Vector3::new_comp(0.0, 0.0, -1.0),
+            Vector3::new_comp(1.0, 1.0, 1.0),
+            Vector3::new_comp(-3.0, 0.5, 2.0),
+            Vector3::new_comp(1e-3, 1e-3, 5.0),
+        ];
+
+        for input in inputs {
+            let orthogonal = input.any_orthogonal();
+            assert!(orthogonal.sqr_magnitude() > 0.0);
+            assert!(f64::abs(Vector3::dot(orthogonal, input)) < 1e-12);
+
+            let (normal, tangent, bitangent) = input.orthonormal_basis();
+            for v in [normal, tangent, bitangent] {
+                assert!(f64::abs(v.magnitude() - 1.0) < 1e-12);
+            }
+
+            assert!(f64::abs(Vector3::dot(normal, tangent)) < 1e-12);
+            assert!(f64::abs(Vector3::dot(normal, bitangent)) < 1e-12);
+            assert!(f64::abs(Vector3::dot(tangent, bitangent)) < 1e-12);
+            assert!(Vector3::distance(Vector3::cross(tangent, bitangent), normal) < 1e-12);
+        }
+    }
+
+    #[test]
+    fn vector_checked_normalize() {
+        let zero = Vector2::new_comp(0.0, 0.0);
+        assert!(zero.normalized().x.is_nan());
+        assert_eq!(zero.try_normalized(), None);
+        assert_eq!(zero.normalized_or_zero(), zero);
+        assert_eq!(Vector2::new_comp(0.0, -2.0).try_normalized(), Some(Vector2::down()));
+
+        let zero = Vector3::new_comp(0.0, 0.0, 0.0);
+        assert!(zero.normalized().x.is_nan());
+        assert_eq!(zero.try_normalized(), None);
+        assert_eq!(zero.normalized_or_zero(), zero);
+        assert_eq!(Vector3::new_comp(3.0, 0.0, 4.0).normalized_or_zero(), Vector3::new_comp(0.6, 0.0, 0.8));
+
+        let zero = Vector4::new_comp(0.0, 0.0, 0.0, 0.0);
+        assert!(zero.normalized().x.is_nan());
+        assert_eq!(zero.try_normalized(), None);
+        assert_eq!(zero.normalized_or_zero(), zero);
+        assert_eq!(Vector4::new_comp(0.0, 0.0, 0.0, 5.0).try_normalized(), Some(Vector4::new_comp(0.0, 0.0, 0.0, 1.0)));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn vector_ops_by_reference() {
+        let scale = Vector2::new_comp(2.0, 4.0);
+        assert_eq!(Vector2::new_comp(3.0, 5.0) * &scale, Vector2::new_comp(6.0, 20.0));
+        assert_eq!(Vector2::new_comp(3.0, 5.0) / &scale, Vector2::new_comp(1.5, 1.25));
+
+        let mut v = Vector2::new_comp(3.0, 5.0);
+        v *= &scale;
+        assert_eq!(v, Vector2::new_comp(6.0, 20.0));
+        v /= &scale;
+        assert_eq!(v, Vector2::new_comp(3.0, 5.0));
+
+        let scale = Vector3::new_comp(2, 3, 4);
+        assert_eq!(Vector3::new_comp(1, 2, 3) * &scale, Vector3::new_comp(2, 6, 12));
+        assert_eq!(Vector3::new_comp(8, 9, 12) / &scale, Vector3::new_comp(4, 3, 3));
+
+        let mut v = Vector3::new_comp(1, 2, 3);
+        v *= &scale;
+        assert_eq!(v, Vector3::new_comp(2, 6, 12));
+        v /= &scale;
+        assert_eq!(v, Vector3::new_comp(1, 2, 3));
+
+        let scale = Vector4::new_comp(2.0, 2.0, 0.5, 0.25);
+        assert_eq!(Vector4::new_comp(1.0, 2.0, 3.0, 4.0) * &scale, Vector4::new_comp(2.0, 4.0, 1.5, 1.0));
+        assert_eq!(Vector4::new_comp(1.0, 2.0, 3.0, 4.0) / &scale, Vector4::new_comp(0.5, 1.0, 6.0, 16.0));
+
+        let mut v = Vector4::new_comp(1.0, 2.0, 3.0, 4.0);
+        v *= &scale;
+        assert_eq!(v, Vector4::new_comp(2.0, 4.0, 1.5, 1.0));
+        v /= &scale;
+        assert_eq!(v, Vector4::new_comp(1.0, 2.0, 3.0, 4.0));
+    }
+
+    #[test]
+    fn vector_extend_truncate() {
+        let v2 = Vector2::new_comp(1, 2);
+        assert_eq!(v2.extend(5), Vector3::new_comp(1, 2, 5));
+        assert_eq!(v2.extend(5).truncate(), v2);
+
+        let v3 = Vector3::new_comp(1, 2, 3);
+        assert_eq!(v3.extend(7), Vector4::new_comp(1, 2, 3, 7));
+        assert_eq!(v3.extend(7).truncate(), v3);
+    }
+
+    #[test]
+    fn vector_swizzles() {
+        let v = Vector4::new_comp(1, 2, 3, 4);
+        assert_eq!(v.xyz(), Vector3::new_comp(1, 2, 3));
+        assert_eq!(v.xy(), Vector2::new_comp(1, 2));
+        assert_eq!(v.zw(), Vector2::new_comp(3, 4));
+        assert_eq!(v.yzw(), Vector3::new_comp(2, 3, 4));
+        assert_eq!(v.wzyx(), Vector4::new_comp(4, 3, 2, 1));
+
+        let v = Vector3::new_comp(1, 2, 3);
+        assert_eq!(v.xz(), Vector2::new_comp(1, 3));
+        assert_eq!(v.zyx(), Vector3::new_comp(3, 2, 1));
+        assert_eq!(Vector2::new_comp(1, 2).yx(), Vector2::new_comp(2, 1));
+    }
+
+    #[test]
+    fn vector_clamp() {
+        let min = Vector2::new_comp(0, 0);
+        let max = Vector2::new_comp(10, 10);
+        assert_eq!(Vector2::new_comp(-5, 5).clamp(min, max), Vector2::new_comp(0, 5));
+        assert_eq!(Vector2::new_comp(3, 5).clamp(Vector2::new_comp(5, 0), Vector2::new_comp(4, 10)), Vector2::new_comp(4, 5));
+
+        let min = Vector3::new_comp(-1.0, -1.0, -1.0);
+        let max = Vector3::new_comp(1.0, 1.0, 1.0);
+        assert_eq!(Vector3::new_comp(0.5, 2.0, -3.0).clamp(min, max), Vector3::new_comp(0.5, 1.0, -1.0));
+
+        let min = Vector4::new_comp(0, 0, 0, 0);
+        let max = Vector4::new_comp(1, 2, 3, 4);
+        assert_eq!(Vector4::new_comp(5, 1, -1, 4).clamp(min, max), Vector4::new_comp(1, 1, 0, 4));
+    }
+
+    #[test]
+    fn vector_angle() {
+        assert_eq!(Vector2::angle_degrees(Vector2::<f64>::right(), Vector2::up()), 90.0);
+        assert_eq!(Vector2::angle_degrees(Vector2::<f64>::right(), Vector2::left()), 180.0);
+        assert_eq!(Vector2::angle(Vector2::new_comp(3.0, 3.0), Vector2::new_comp(1.0, 1.0)), 0.0);
+        assert_eq!(Vector2::angle(Vector2::new_comp(0.0, 0.0), Vector2::up()), 0.0);
+
+        assert_eq!(Vector3::angle_degrees(Vector3::<f64>::right(), Vector3::up()), 90.0);
+        assert!(f64::abs(Vector3::angle_degrees(Vector3::new_comp(1.0, 0.0, 0.0), Vector3::new_comp(1.0, 1.0, 0.0)) - 45.0) < 1e-12);
+
+        let a = Vector4::new_comp(1.0, 0.0, 0.0, 0.0);
+        let b = Vector4::new_comp(0.0, 0.0, 0.0, -2.0);
+        assert_eq!(Vector4::angle_degrees(a, b), 90.0);
+    }
+
+    #[test]
+    fn vector_sum_and_average() {
+        let points = [
+            Vector2::new_comp(1.0, 2.0),
+            Vector2::new_comp(3.0, -4.0),
+            Vector2::new_comp(5.0, 8.0),
+        ];
+
+        assert_eq!(Vector2::sum(points), Vector2::new_comp(9.0, 6.0));
+        assert_eq!(Vector2::average(points), Some(Vector2::new_comp(3.0, 2.0)));
+        assert_eq!(Vector2::<f64>::average(Vec::new()), None);
+
+        let points = [Vector3::new_comp(0.0, 0.0, 0.0), Vector3::new_comp(2.0, 4.0, -6.0)];
+        assert_eq!(Vector3::average(points.iter().copied()), Some(Vector3::new_comp(1.0, 2.0, -3.0)));
+
+        let points = [Vector4::new_comp(1.0, 1.0, 1.0, 1.0); 4];
+        assert_eq!(Vector4::sum(points), Vector4::new_comp(4.0, 4.0, 4.0, 4.0));
+        assert_eq!(Vector4::average(points), Some(Vector4::new_comp(1.0, 1.0, 1.0, 1.0)));
+    }
+
+    #[test]
+    fn vector2_reflect_across() {
+        let vector = Vector2::new_comp(2.0, 3.0);
+
+        assert_eq!(vector.reflect_across(Vector2::new_comp(1.0, 0.0)), Vector2::new_comp(2.0, -3.0));
+        assert_eq!(vector.reflect_across(Vector2::new_comp(-5.0, 0.0)), Vector2::new_comp(2.0, -3.0));
+        assert_eq!(vector.reflect_across(Vector2::new_comp(0.0, 2.0)), Vector2::new_comp(-2.0, 3.0));
+        assert_eq!(vector.reflect_across(Vector2::new_comp(1.0, 1.0)), Vector2::new_comp(3.0, 2.0));
+        assert_eq!(vector.reflect_across(Vector2::new_comp(4.0, 4.0)), Vector2::new_comp(3.0, 2.0));
+    }
+
+    #[test]
+    fn vector_try_from_slice() {
+        let values = [1.0, 2.0, 3.0, 4.0];
+
+        assert_eq!(Vector3::try_from(&values[..3]), Ok(Vector3::new_comp(1.0, 2.0, 3.0)));
+        assert_eq!(Vector3::try_from(&values[..2]), Err(LengthError { expected: 3, actual: 2 }));
+        assert_eq!(Vector3::try_from(&values[..]), Err(LengthError { expected: 3, actual: 4 }));
+
+        assert_eq!(Vector2::try_from(&values[2..]), Ok(Vector2::new_comp(3.0, 4.0)));
+        assert_eq!(Vector4::try_from(&values[..]), Ok(Vector4::new_comp(1.0, 2.0, 3.0, 4.0)));
+        assert_eq!(Vector4::<f64>::try_from(&[][..]).unwrap_err().to_string(), "expected 4 components, got 0");
+    }
+
+    #[test]
+    fn vector3_slerp() {
+        let right = Vector3::<f64>::right();
+        let up = Vector3::up();
+
+        let halfway = Vector3::slerp(right, up, 0.5);
+        assert!(f64::abs(halfway.magnitude() - 1.0) < 1e-12);
+        assert!(f64::abs(Vector3::angle_degrees(right, halfway) - 45.0) < 1e-9);
+        assert!(Vector3::distance(halfway, Vector3::new_comp(1.0, 1.0, 0.0) / 2.0f64.sqrt()) < 1e-12);
+
+        assert!(Vector3::distance(Vector3::slerp(right, up, 0.0), right) < 1e-12);
+        assert!(Vector3::distance(Vector3::slerp(right, up, 1.0), up) < 1e-12);
+        assert_eq!(Vector3::slerp(right, right, 0.3), right);
+    }
+
+    #[test]
+    fn vector2_angle_round_trip() {
+        assert_eq!(Vector2::from_angle(0.0), Vector2::right());
+        assert!(Vector2::distance(Vector2::from_angle(std::f64::consts::FRAC_PI_2), Vector2::up()) < 1e-12);
+        assert!(Vector2::distance(Vector2::from_polar(2.0, std::f64::consts::PI), Vector2::new_comp(-2.0, 0.0)) < 1e-12);
+
+        for angle in [-3.0, -1.5, -0.25, 0.0, 0.5, 2.0, std::f64::consts::PI] {
+            assert!(f64::abs(Vector2::from_angle(angle).to_angle() - angle) < 1e-12);
+            assert!(f64::abs(Vector2::from_polar(3.5, angle).to_angle() - angle) < 1e-12);
+        }
+    }
+
+    #[test]
+    fn vector_cast() {
+        let precise = Vector3::new_comp(1.5f64, -2.25, 1e10);
+        assert_eq!(precise.cast::<f32>(), Some(Vector3::new_comp(1.5f32, -2.25, 1e10)));
+        assert_eq!(Vector3::new_comp(1.0f64, 1e300, 0.0).cast::<f32>(), Some(Vector3::new_comp(1.0, f32::INFINITY, 0.0)));
+        assert_eq!(Vector3::new_comp(1.0f64, f64::NAN, 0.0).cast::<i32>(), None);
+
+        assert_eq!(Vector2::new_comp(3i32, -4).cast::<f64>(), Some(Vector2::new_comp(3.0, -4.0)));
+        assert_eq!(Vector2::new_comp(2.9f64, -1.5).cast::<i32>(), Some(Vector2::new_comp(2, -1)));
+        assert_eq!(Vector2::new_comp(-1i32, 1).cast::<u8>(), None);
+
+        assert_eq!(Vector4::new_comp(1u8, 2, 3, 4).cast::<f32>(), Some(Vector4::new_comp(1.0, 2.0, 3.0, 4.0)));
+    }
+
+    #[test]
+    fn vector_component_extremes() {
+        let vector = Vector3::new_comp(1.0, -2.0, 5.0);
+        assert_eq!(vector.max_component(), 5.0);
+        assert_eq!(vector.max_axis(), 2);
+        assert_eq!(vector.min_component(), -2.0);
+        assert_eq!(vector.min_axis(), 1);
+
+        assert_eq!(Vector2::new_comp(3, 3).max_axis(), 0);
+        assert_eq!(Vector2::new_comp(3, 3).min_axis(), 0);
+        assert_eq!(Vector2::new_comp(1, 3).max_component(), 3);
+
+        let vector = Vector4::new_comp(0, 7, -1, 7);
+        assert_eq!(vector.max_axis(), 1);
+        assert_eq!(vector.min_axis(), 2);
+        assert_eq!(vector.min_component(), -1);
+    }
+
+    #[test]
+    fn vector_snap_to_grid() {
+        let vector = Vector2::new_comp(1.4, 2.6);
+        assert_eq!(vector.snap_to_grid_uniform(1.0), Vector2::new_comp(1.0, 3.0));
+        assert_eq!(vector.snap_to_grid(Vector2::new_comp(0.5, 2.0)), Vector2::new_comp(1.5, 2.0));
+        assert_eq!(vector.snap_to_grid(Vector2::new_comp(0.0, 1.0)), Vector2::new_comp(1.4, 3.0));
+        assert_eq!(vector.snap_floor(Vector2::new_comp(1.0, 1.0)), Vector2::new_comp(1.0, 2.0));
+        assert_eq!(Vector2::new_comp(-0.5, -1.5).snap_floor(Vector2::new_comp(1.0, 1.0)), Vector2::new_comp(-1.0, -2.0));
+
+        assert_eq!(Vector3::new_comp(7.0, -7.0, 12.4).snap_to_grid_uniform(5.0), Vector3::new_comp(5.0, -5.0, 10.0));
+        assert_eq!(Vector4::new_comp(7.0, 3.0, 9.9, 0.1).snap_floor(Vector4::new_comp(5.0, 5.0, 5.0, 5.0)), Vector4::new_comp(5.0, 0.0, 5.0, 0.0));
+    }
+
+    #[test]
+    fn vector4_homogeneous() {
+        let point = Vector4::from_point(Vector3::new_comp(1.0, 2.0, 3.0));
+        assert_eq!(point, Vector4::new_comp(1.0, 2.0, 3.0, 1.0));
+        assert_eq!(point.perspective_divide(), Some(Vector3::new_comp(1.0, 2.0, 3.0)));
+
+        let scaled = Vector4::new_comp(2.0, 4.0, -6.0, 2.0);
+        assert_eq!(scaled.perspective_divide(), Some(Vector3::new_comp(1.0, 2.0, -3.0)));
+
+        let direction = Vector4::from_direction(Vector3::new_comp(0.0, 1.0, 0.0));
+        assert_eq!(direction.w, 0.0);
+        assert_eq!(direction.perspective_divide(), None);
+    }
+
+    #[test]
+    fn vector3_rotate_around_axis() {
+        let quarter = std::f64::consts::FRAC_PI_2;
+
+        let rotated = Vector3::<f64>::right().rotate_around_axis(Vector3::up(), quarter);
+        assert!(Vector3::distance(rotated, Vector3::back()) < 1e-12);
+
+        let rotated = Vector3::<f64>::right().rotate_around_axis(Vector3::new_comp(0.0, 0.0, 3.0), quarter);
+        assert!(Vector3::distance(rotated, Vector3::up()) < 1e-12);
+
+        let vector = Vector3::new_comp(1.0, 2.0, 3.0);
+        assert_eq!(vector.rotate_around_axis(Vector3::new_comp(0.0, 0.0, 0.0), quarter), vector);
+        assert!(Vector3::distance(vector.rotate_around_axis(vector, 1.0), vector) < 1e-12);
+    }
+
+    #[test]
+    fn vector_finite_and_nan() {
+        let vector = Vector2::new_comp(1.0f32, -2.0);
+        assert!(vector.is_finite());
+        assert!(!vector.has_nan());
+
+        let nan = Vector3::new_comp(1.0f32, f32::NAN, 0.0);
+        assert!(!nan.is_finite());
+        assert!(nan.has_nan());
+
+        let infinite = Vector4::new_comp(0.0f32, 0.0, 0.0, f32::INFINITY);
+        assert!(!infinite.is_finite());
+        assert!(!infinite.has_nan());
+    }
+
+    #[test]
+    fn vector2_perp_dot() {
+        assert_eq!(Vector2::perp_dot(Vector2::new_comp(1.0, 0.0), Vector2::new_comp(0.0, 1.0)), 1.0);
+        assert_eq!(Vector2::perp_dot(Vector2::new_comp(0.0, 1.0), Vector2::new_comp(1.0, 0.0)), -1.0);
+        assert_eq!(Vector2::perp_dot(Vector2::new_comp(2.0, 2.0), Vector2::new_comp(-3.0, -3.0)), 0.0);
+    }
+
+    #[test]
+    fn vector_map_and_zip_map() {
+        assert_eq!(Vector2::new_comp(2.0, -3.0).map(|c| c * c), Vector2::new_comp(4.0, 9.0));
+        assert_eq!(Vector3::new_comp(1, 2, 3).map(|c| c as f32 * 0.5), Vector3::new_comp(0.5, 1.0, 1.5));
+        assert_eq!(Vector4::new_comp(1.0, -2.0, 3.0, -4.0).map(f64::abs), Vector4::new_comp(1.0, 2.0, 3.0, 4.0));
+
+        let a = Vector3::new_comp(1.0, 5.0, -2.0);
+        let b = Vector3::new_comp(3.0, 4.0, -1.0);
+        assert_eq!(a.zip_map(b, f64::min), Vector3::new_comp(1.0, 4.0, -2.0));
+        assert_eq!(Vector2::new_comp(1, 2).zip_map(Vector2::new_comp(true, false), |c, keep| if keep { c } else { 0 }), Vector2::new_comp(1, 0));
+        assert_eq!(Vector4::new_comp(1, 2, 3, 4).zip_map(Vector4::new_comp(4, 3, 2, 1), i32::max), Vector4::new_comp(4, 3, 3, 4));
+    }
+
+    #[test]
+    fn vector2_parallel_and_perpendicular() {
+        let a = Vector2::new_comp(2.0, 1.0);
+        assert!(a.is_parallel(&Vector2::new_comp(-4.0, -2.0), 1e-12));
+        assert!(!a.is_perpendicular(&Vector2::new_comp(-4.0, -2.0), 1e-12));
+
+        assert!(a.is_perpendicular(&Vector2::new_comp(-1.0, 2.0), 1e-12));
+        assert!(!a.is_parallel(&Vector2::new_comp(-1.0, 2.0), 1e-12));
+
+        let between = Vector2::new_comp(1.0, 1.0);
+        assert!(!a.is_parallel(&between, 1e-12));
+        assert!(!a.is_perpendicular(&between, 1e-12));
+        assert!(a.is_parallel(&Vector2::new_comp(2.0, 1.001), 0.01));
+    }
+
+    #[test]
+    fn vector3_parallel_and_perpendicular() {
+        let a = Vector3::new_comp(1.0, 2.0, 3.0);
+        assert!(a.is_parallel(&Vector3::new_comp(3.0, 6.0, 9.0), 1e-12));
+        assert!(a.is_parallel(&Vector3::new_comp(-1.0, -2.0, -3.0), 1e-12));
+        assert!(!a.is_perpendicular(&Vector3::new_comp(3.0, 6.0, 9.0), 1e-12));
+
+        assert!(a.is_perpendicular(&Vector3::new_comp(3.0, 0.0, -1.0), 1e-12));
+        assert!(!a.is_parallel(&Vector3::new_comp(3.0, 0.0, -1.0), 1e-12));
+
+        let between = Vector3::new_comp(1.0, 0.0, 0.0);
+        assert!(!a.is_parallel(&between, 1e-12));
+        assert!(!a.is_perpendicular(&between, 1e-12));
+    }
+
+    #[test]
+    fn vector_from_column_arrays() {
+        assert_eq!(Vector2::from([[1], [2]]), Vector2::new_comp(1, 2));
+        assert_eq!(Vector3::from([[1], [2], [3]]), Vector3::new_comp(1, 2, 3));
+        assert_eq!(Vector4::from([[1], [2], [3], [4]]), Vector4::new_comp(1, 2, 3, 4));
+    }
+
+    #[test]
+    fn vector4_parallel_and_perpendicular() {
+        let a = Vector4::new_comp(1.0, 2.0, 3.0, 4.0);
+        assert!(a.is_parallel(&Vector4::new_comp(2.0, 4.0, 6.0, 8.0), 1e-9));
+        assert!(a.is_parallel(&Vector4::new_comp(-0.5, -1.0, -1.5, -2.0), 1e-9));
+        assert!(!a.is_perpendicular(&Vector4::new_comp(2.0, 4.0, 6.0, 8.0), 1e-9));
+
+        let perpendicular = Vector4::new_comp(4.0, 0.0, 0.0, -1.0);
+        assert!(a.is_perpendicular(&perpendicular, 1e-12));
+        assert!(!a.is_parallel(&perpendicular, 1e-9));
+
+        let between = Vector4::new_comp(1.0, 0.0, 0.0, 0.0);
+        assert!(!a.is_parallel(&between, 1e-9));
+        assert!(!a.is_perpendicular(&between, 1e-9));
+
+        let a3 = Vector3::new_comp(1.0, 2.0, 3.0);
+        let b3 = Vector3::new_comp(2.0, -1.0, 0.5);
+        let wedge = Vector3::cross(a3, b3).magnitude();
+        let (a4, b4) = (Vector4::new_comp(1.0, 2.0, 3.0, 0.0), Vector4::new_comp(2.0, -1.0, 0.5, 0.0));
+        assert!(a4.is_parallel(&b4, wedge + 1e-9));
+        assert!(!a4.is_parallel(&b4, wedge - 1e-9));
+    }
+
+    #[test]
+    fn vector3_sum() {
+        let vectors = [
+            Vector3::new_comp(1.0, 2.0, 3.0),
+            Vector3::new_comp(4.0, 5.0, 6.0),
+            Vector3::new_comp(-2.0, 0.5, 1.0),
+        ];
+
+        let sum = vectors.iter().copied().sum::<Vector3<f32>>();
+        assert_eq!(sum, vectors[0] + vectors[1] + vectors[2]);
+        assert_eq!(sum / vectors.len() as f32, Vector3::new_comp(1.0, 2.5, 10.0 / 3.0));
+    }
+
+    #[test]
+    fn vector_product() {
+        let vectors = [Vector2::new_comp(2, 3), Vector2::new_comp(4, 5)];
+        assert_eq!(vectors.into_iter().product::<Vector2<i32>>(), Vector2::new_comp(8, 15));
+
+        let empty: Vec<Vector4<f64>> = Vec::new();
+        assert_eq!(empty.into_iter().sum::<Vector4<f64>>(), Vector4::new_comp(0.0, 0.0, 0.0, 0.0));
+    }
 }